@@ -0,0 +1,3 @@
+fn main() {
+  std::process::exit(relic_fullstack::run_integrity());
+}
@@ -98,6 +98,28 @@ impl fmt::Display for Capability {
   }
 }
 
+impl Capability {
+  pub fn ordered() -> Vec<Capability> {
+    return vec![
+      Capability::Physique,
+      Capability::Warfare,
+      Capability::Spirit,
+      Capability::Manipulation,
+    ];
+  }
+}
+
+impl From<Capability> for CharacterAttribute {
+  fn from(capability: Capability) -> Self {
+    return match capability {
+      Capability::Physique => CharacterAttribute::Physique,
+      Capability::Warfare => CharacterAttribute::Warfare,
+      Capability::Spirit => CharacterAttribute::Spirit,
+      Capability::Manipulation => CharacterAttribute::Manipulation,
+    };
+  }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub enum Defense {
   Tenacity,
@@ -122,3 +144,15 @@ impl fmt::Display for Defense {
     )
   }
 }
+
+impl Defense {
+  pub fn ordered() -> Vec<Defense> {
+    return vec![
+      Defense::Tenacity,
+      Defense::Fortitude,
+      Defense::Resolve,
+      Defense::Insight,
+      Defense::Dodge,
+    ];
+  }
+}
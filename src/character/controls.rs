@@ -0,0 +1,109 @@
+use dioxus::prelude::*;
+
+use crate::server::prelude::{delete_character_sheet, update_character_sheet, CharacterSheetCache};
+use crate::progression::prelude::MAX_LEVEL;
+use crate::Route;
+
+use super::sheet::CharacterSheet;
+
+#[derive(Debug, Clone, PartialEq)]
+enum WriteStatus {
+  Idle,
+  Writing,
+  ConfirmDelete,
+  Errored(String),
+}
+
+#[component]
+pub fn SheetControls(sheet: CharacterSheet) -> Element {
+  let CharacterSheetCache(sheet_cache) = use_context::<CharacterSheetCache>();
+  let mut name: Signal<String> = use_signal(|| sheet.name.clone());
+  let mut status: Signal<WriteStatus> = use_signal(|| WriteStatus::Idle);
+  let nav = navigator();
+  let mut renamed = sheet.clone();
+  renamed.name = name().trim().into();
+  let errors = renamed.validation_errors();
+  let writing = status() == WriteStatus::Writing;
+  let save_class = if writing || !errors.is_empty() { "disabled" } else { "" };
+  let delete_class = if writing { "disabled" } else { "" };
+  let update_cache = sheet_cache.clone();
+  let delete_cache = sheet_cache.clone();
+  let id = sheet.id.to_hex();
+  let revision = sheet.revision;
+  let level_up_id = id.clone();
+  let play_id = id.clone();
+  let can_level_up = sheet.level < MAX_LEVEL;
+  rsx! {
+    div {
+      class: "row align-center no-print underhang",
+      input {
+        class: "input-text", r#type: "text",
+        value: name(),
+        oninput: move |event| { name.set(event.value()); },
+      }
+      div {
+        class: "next-button {save_class}",
+        onclick: move |_| {
+          if writing || !errors.is_empty() {
+            return;
+          }
+          let updated = renamed.clone();
+          let cache = update_cache.clone();
+          status.set(WriteStatus::Writing);
+          spawn(async move {
+            match update_character_sheet(updated).await {
+              Ok(_) => {
+                status.set(WriteStatus::Idle);
+                cache.restart();
+              },
+              Err(error) => status.set(WriteStatus::Errored(error.to_string())),
+            }
+          });
+        },
+        "Save"
+      }
+      div {
+        class: "next-button errored {delete_class}",
+        onclick: move |_| {
+          match status() {
+            WriteStatus::Writing => (),
+            WriteStatus::ConfirmDelete => {
+              let id = id.clone();
+              let cache = delete_cache.clone();
+              status.set(WriteStatus::Writing);
+              spawn(async move {
+                match delete_character_sheet(id, revision).await {
+                  Ok(_) => {
+                    cache.restart();
+                    nav.push(Route::CharacterSheetsPage {});
+                  },
+                  Err(error) => status.set(WriteStatus::Errored(error.to_string())),
+                }
+              });
+            },
+            _ => status.set(WriteStatus::ConfirmDelete),
+          }
+        },
+        match status() {
+          WriteStatus::ConfirmDelete => "Confirm delete",
+          _ => "Delete",
+        }
+      }
+      Link {
+        class: "next-button",
+        to: Route::PlayPage { id: play_id, session: "default".into() },
+        "Play"
+      }
+      if can_level_up {
+        Link {
+          class: "next-button",
+          to: Route::LevelUpPage { id: level_up_id },
+          "Level up"
+        }
+      }
+      if let WriteStatus::Errored(error) = status() {
+        div { class: "errored", "{error}" }
+      }
+    }
+  }
+}
@@ -3,6 +3,7 @@ use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use super::components::AttributeRow;
+use super::layers::StatLayers;
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -10,11 +11,24 @@ pub struct ExpertiseEntry {
   pub title: Option<String>,
   pub ranks: Option<i32>,
   pub empty: Option<bool>,
+  pub specialization: Option<i32>,
+  pub enchantment: Option<i32>,
+}
+
+impl ExpertiseEntry {
+  pub fn layers(&self) -> Option<StatLayers> {
+    return Some(StatLayers {
+      ranks: self.ranks?,
+      specialization: self.specialization.unwrap_or_default(),
+      enchantment: self.enchantment.unwrap_or_default(),
+    });
+  }
 }
 
 #[component]
 pub fn ExpertiseComponent(entry: ExpertiseEntry) -> Element {
-  return match (entry.title, entry.ranks, entry.empty) {
+  let total = entry.layers().map(|layers| layers.total());
+  return match (entry.title, total, entry.empty) {
     (_, _, Some(true)) => rsx!(div {
       class: "underline-border full lh"
     }),
@@ -0,0 +1,325 @@
+use std::fmt;
+
+use dioxus::prelude::*;
+
+use crate::common::*;
+use crate::equipment::armor::Armor;
+use crate::equipment::inventory::{self, InventoryItem, Item};
+use crate::equipment::shield::Shield;
+use crate::server::prelude::{
+  sheet_with_catalog, update_character_sheet, ArmorCache, CharacterSheetCache, SkillCache,
+  WeaponCache,
+};
+
+use super::resistance::DamageCategory;
+use super::sheet::CharacterSheet;
+
+#[derive(Debug, Clone, PartialEq)]
+enum ItemKind {
+  Gear,
+  Armor,
+  Shield,
+}
+
+impl fmt::Display for ItemKind {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        ItemKind::Gear => "Gear",
+        ItemKind::Armor => "Armor",
+        ItemKind::Shield => "Shield",
+      }
+    )
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Loadout {
+  title: String,
+  equipped: bool,
+  wearable: bool,
+  dodge: i32,
+  speed: i32,
+  dash: i32,
+  physical: i32,
+}
+
+#[component]
+pub fn SheetInventory(sheet: CharacterSheet) -> Element {
+  let CharacterSheetCache(sheet_cache) = use_context::<CharacterSheetCache>();
+  let SkillCache(ref skill_map) = use_context::<SkillCache>();
+  let ArmorCache(ref armor_cache) = use_context::<ArmorCache>();
+  let WeaponCache(ref weapon_cache) = use_context::<WeaponCache>();
+  let mut items: Signal<Vec<InventoryItem>> = use_signal(|| sheet.inventory());
+  let mut status: Signal<SaveStatus> = use_signal(|| SaveStatus::Idle);
+  let mut message: Signal<Option<String>> = use_signal(|| None);
+  let mut new_title: Signal<String> = use_signal(String::new);
+  let mut new_kind: Signal<ItemKind> = use_signal(|| ItemKind::Gear);
+  let mut new_armor: Signal<Armor> = use_signal(Armor::default);
+  let mut new_shield: Signal<Shield> = use_signal(Shield::default);
+
+  let current = items();
+  let mut draft_sheet = sheet.clone();
+  draft_sheet.set_inventory(current.clone());
+  let resolved_sheet = sheet_with_catalog(&draft_sheet);
+  let resolved = resolved_sheet.inventory();
+  let skills = skill_map.from_object_ids(&sheet.skills);
+  let equipped = inventory::equipped_armor(&resolved);
+  let shield = inventory::equipped_shield(&resolved);
+  let mut options: Vec<Option<Armor>> = vec![None];
+  for entry in &resolved {
+    if let Item::Armor(armor) = &entry.item {
+      options.push(Some(armor.clone()));
+    }
+  }
+  let loadouts: Vec<Loadout> = options
+    .into_iter()
+    .map(|armor| {
+      let derived = resolved_sheet.derive_with_gear(&skills, &armor, &shield);
+      let title = armor.as_ref().map(|armor| armor.title.clone()).unwrap_or("Unarmored".into());
+      Loadout {
+        title: match &shield {
+          Some(shield) => format!("{title} + {}", shield.title),
+          None => title,
+        },
+        equipped: armor == equipped,
+        wearable: derived.bulk_fits,
+        dodge: derived.attributes.dodge.unwrap_or_default(),
+        speed: derived.body.speed,
+        dash: derived.dash,
+        physical: derived.resistances.get_category(&DamageCategory::Physical),
+      }
+    })
+    .collect();
+
+  let errors = resolved_sheet.validation_errors();
+  let valid = errors.is_empty();
+  let updated = draft_sheet;
+  let dirty = Some(current.clone()) != sheet.inventory;
+  let saving = status() == SaveStatus::Saving;
+  let save_class = if saving || !dirty || !valid { "disabled" } else { "" };
+  let add_class = if new_title().trim().is_empty() { "disabled" } else { "" };
+  let kind = new_kind();
+  let armor_draft = new_armor();
+  let shield_draft = new_shield();
+  let mut catalog: Vec<(String, Item)> = Vec::new();
+  for armor in armor_cache.into_vec() {
+    catalog.push((format!("{} (armor)", armor.title), Item::CatalogArmor(armor.id)));
+  }
+  for weapon in weapon_cache.into_vec() {
+    catalog.push((format!("{} ({})", weapon.title, weapon.class), Item::CatalogWeapon(weapon.id)));
+  }
+  catalog.sort_by(|a, b| a.0.cmp(&b.0));
+  rsx! {
+    div {
+      class: "card grid dim-keywords no-print underhang",
+      div { class: "uv-full subheading", "Inventory" }
+      div {
+        class: "uv-full row align-center",
+        div {
+          class: "next-button {save_class}",
+          onclick: move |_| {
+            if saving || !dirty || !valid {
+              return;
+            }
+            let updated = updated.clone();
+            let cache = sheet_cache.clone();
+            status.set(SaveStatus::Saving);
+            spawn(async move {
+              match update_character_sheet(updated).await {
+                Ok(_) => {
+                  status.set(SaveStatus::Idle);
+                  cache.restart();
+                },
+                Err(error) => status.set(SaveStatus::Errored(error.to_string())),
+              }
+            });
+          },
+          match status() {
+            SaveStatus::Saving => "Saving ...",
+            _ => "Save inventory",
+          }
+        }
+        if dirty {
+          div { class: "italics", "Unsaved changes" }
+        }
+        for error in errors {
+          div { class: "errored", "{error}" }
+        }
+        if let SaveStatus::Errored(error) = status() {
+          div { class: "errored", "{error}" }
+        }
+        if let Some(error) = message() {
+          div { class: "errored", "{error}" }
+        }
+      }
+      for loadout in loadouts {
+        FormRow {
+          title: loadout.title.clone(),
+          if loadout.equipped {
+            div { class: "highlight", "Equipped" }
+          }
+          if loadout.wearable {
+            div { "Dodge {loadout.dodge}" }
+            div { "Speed {loadout.speed}" }
+            div { "Dash {loadout.dash}" }
+            div { "Physical {loadout.physical}" }
+          } else {
+            div { class: "errored", "Too bulky to wear" }
+          }
+        }
+      }
+      for (index, entry) in resolved.clone().into_iter().enumerate() {
+        FormRow {
+          title: entry.item.title(),
+          NumberField {
+            value: entry.quantity,
+            onchange: move |value: i32| items.write()[index].quantity = value.max(0),
+          }
+          OptionalTextField {
+            value: entry.notes.clone(),
+            onchange: move |value| items.write()[index].notes = value,
+          }
+          match &entry.slot {
+            Some(slot) => rsx! {
+              div { class: "highlight", "{slot}" }
+              div {
+                class: "chip",
+                onclick: {
+                  let id = entry.id;
+                  move |_| inventory::unequip(&mut items.write(), &id)
+                },
+                "Unequip"
+              }
+            },
+            None => rsx! {
+              for slot in entry.item.slots() {
+                div {
+                  class: "chip",
+                  onclick: {
+                    let (id, slot, resolved) = (entry.id, slot.clone(), resolved.clone());
+                    move |_| {
+                      let result =
+                        inventory::equip(&mut items.write(), &resolved, &id, slot.clone());
+                      message.set(result.err());
+                    }
+                  },
+                  "Equip {slot}"
+                }
+              }
+            },
+          }
+          div {
+            class: "chip",
+            onclick: move |_| {
+              items.write().remove(index);
+            },
+            "Remove ✕"
+          }
+        }
+      }
+      FormRow {
+        title: "Add item",
+        TextField { value: new_title(), onchange: move |value| new_title.set(value) }
+        EnumSelect {
+          options: vec![ItemKind::Gear, ItemKind::Armor, ItemKind::Shield],
+          selected: Some(kind.clone()),
+          onchange: move |value: Option<ItemKind>| {
+            if let Some(kind) = value {
+              new_kind.set(kind);
+            }
+          },
+        }
+        div {
+          class: "next-button {add_class}",
+          onclick: move |_| {
+            let title: String = new_title().trim().into();
+            if title.is_empty() {
+              return;
+            }
+            let item = match new_kind() {
+              ItemKind::Gear => Item::Gear(title),
+              ItemKind::Armor => Item::Armor(Armor { title, ..new_armor() }),
+              ItemKind::Shield => Item::Shield(Shield { title, ..new_shield() }),
+            };
+            items.write().push(InventoryItem::new(item));
+            new_title.set(String::new());
+          },
+          "Add"
+        }
+      }
+      if !catalog.is_empty() {
+        FormRow {
+          title: "Add from catalog",
+          select {
+            onchange: move |event| {
+              let Some((_, item)) = event
+                .value()
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| catalog.get(index)) else {
+                return;
+              };
+              items.write().push(InventoryItem::new(item.clone()));
+            },
+            option { value: "", selected: true, "Choose equipment" }
+            for (index, (label, _)) in catalog.iter().enumerate() {
+              option { value: "{index}", "{label}" }
+            }
+          }
+        }
+      }
+      if kind == ItemKind::Armor {
+        FormRow {
+          title: "Armor",
+          NumberField {
+            value: armor_draft.physical_resistance,
+            onchange: move |value| new_armor.write().physical_resistance = value,
+          }
+          div { "Bulk" }
+          NumberField {
+            value: armor_draft.tenacity_requirement,
+            onchange: move |value| new_armor.write().tenacity_requirement = value,
+          }
+          div { "Drag" }
+          OptionalNumberField {
+            value: armor_draft.speed_penalty,
+            onchange: move |value| new_armor.write().speed_penalty = value,
+          }
+        }
+      }
+      if kind == ItemKind::Shield {
+        FormRow {
+          title: "Block",
+          NumberField {
+            value: shield_draft.block,
+            onchange: move |value| new_shield.write().block = value,
+          }
+          div { "Bulk" }
+          NumberField {
+            value: shield_draft.tenacity_requirement,
+            onchange: move |value| new_shield.write().tenacity_requirement = value,
+          }
+          for category in DamageCategory::ordered() {
+            CheckboxField {
+              checked: shield_draft.categories.contains(&category),
+              onchange: {
+                let category = category.clone();
+                move |checked: bool| {
+                  let mut shield = new_shield.write();
+                  shield.categories.retain(|existing| existing != &category);
+                  if checked {
+                    shield.categories.push(category.clone());
+                  }
+                }
+              },
+            }
+            div { "{category}" }
+          }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,147 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::attribute::CharacterAttribute;
+use super::sheet::CharacterSheet;
+use crate::common::*;
+use crate::server::prelude::{update_character_sheet, CharacterSheetCache};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StatLayers {
+  pub ranks: i32,
+  pub specialization: i32,
+  pub enchantment: i32,
+}
+
+impl fmt::Display for StatLayers {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let mut parts = vec![format!("{} ranks", self.ranks)];
+    if self.specialization != 0 {
+      parts.push(format!("{:+} spec", self.specialization));
+    }
+    if self.enchantment != 0 {
+      parts.push(format!("{:+} enchant", self.enchantment));
+    }
+    write!(f, "{}", parts.join(" "))
+  }
+}
+
+impl StatLayers {
+  pub fn trained(&self) -> i32 {
+    return self.ranks + self.specialization;
+  }
+
+  pub fn total(&self) -> i32 {
+    return self.trained() + self.enchantment;
+  }
+
+  pub fn is_layered(&self) -> bool {
+    return self.specialization != 0 || self.enchantment != 0;
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AttributeLayer {
+  pub attribute: CharacterAttribute,
+  pub layers: StatLayers,
+}
+
+#[component]
+pub fn LayerBreakdown(layers: Vec<(String, StatLayers)>) -> Element {
+  let layered: Vec<(String, StatLayers)> =
+    layers.into_iter().filter(|(_, layers)| layers.is_layered()).collect();
+  rsx! {
+    for (name, layers) in layered {
+      div {
+        class: "row full mini-text",
+        div { class: "indent", "{name}" }
+        div { class: "align-right", "{layers}" }
+      }
+    }
+  }
+}
+
+#[component]
+pub fn SheetEnchantments(sheet: CharacterSheet) -> Element {
+  let CharacterSheetCache(sheet_cache) = use_context::<CharacterSheetCache>();
+  let mut layers: Signal<Vec<AttributeLayer>> = use_signal(|| sheet.attribute_layers());
+  let mut expertise = use_signal(|| sheet.expertise.clone().unwrap_or_default());
+  let mut status: Signal<SaveStatus> = use_signal(|| SaveStatus::Idle);
+  let current = layers();
+  let current_expertise = expertise();
+  let mut updated = sheet.clone();
+  updated.set_attribute_layers(current.clone());
+  if sheet.expertise.is_some() {
+    updated.expertise = Some(current_expertise.clone());
+  }
+  let dirty = current != sheet.attribute_layers()
+    || current_expertise != sheet.expertise.clone().unwrap_or_default();
+  let errors = updated.validation_errors();
+  let saving = status() == SaveStatus::Saving;
+  let save_class = if saving || !dirty || !errors.is_empty() { "disabled" } else { "" };
+  rsx! {
+    div {
+      class: "card grid dim-keywords no-print underhang",
+      div { class: "uv-full subheading", "Enchantments" }
+      for (index, layer) in current.into_iter().enumerate() {
+        FormRow {
+          title: layer.attribute.to_string(),
+          div { "{layer.layers.ranks} ranks" }
+          div { "{layer.layers.specialization:+} spec" }
+          NumberField {
+            value: layer.layers.enchantment,
+            onchange: move |value| layers.write()[index].layers.enchantment = value,
+          }
+        }
+      }
+      for (index, entry) in current_expertise.into_iter().enumerate() {
+        if let (Some(title), Some(ranks)) = (entry.title.clone(), entry.ranks) {
+          FormRow {
+            title,
+            div { "{ranks} ranks" }
+            div { "{entry.specialization.unwrap_or_default():+} spec" }
+            NumberField {
+              value: entry.enchantment.unwrap_or_default(),
+              onchange: move |value| {
+                expertise.write()[index].enchantment = if value == 0 { None } else { Some(value) };
+              },
+            }
+          }
+        }
+      }
+      div {
+        class: "uv-full row align-center",
+        div {
+          class: "next-button {save_class}",
+          onclick: move |_| {
+            if saving || !dirty || !updated.validation_errors().is_empty() {
+              return;
+            }
+            let updated = updated.clone();
+            let cache = sheet_cache.clone();
+            status.set(SaveStatus::Saving);
+            spawn(async move {
+              match update_character_sheet(updated).await {
+                Ok(_) => {
+                  status.set(SaveStatus::Idle);
+                  cache.restart();
+                },
+                Err(error) => status.set(SaveStatus::Errored(error.to_string())),
+              }
+            });
+          },
+          if saving { "Saving ..." } else { "Save enchantments" }
+        }
+        for error in errors {
+          div { class: "errored", "{error}" }
+        }
+        if let SaveStatus::Errored(error) = status() {
+          div { class: "errored", "{error}" }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,24 @@
+use dioxus::prelude::*;
+
+use crate::server::prelude::check_character_legality;
+
+#[component]
+pub fn SheetLegality(id: String) -> Element {
+  let violations = use_resource(use_reactive!(|id| async move {
+    check_character_legality(id).await
+  }));
+  let result = violations.read().clone();
+  rsx! {
+    match result {
+      None => rsx! { div { "Checking build legality ..." } },
+      Some(Err(error)) => rsx! { div { class: "errored", "Unable to check legality: {error}" } },
+      Some(Ok(violations)) if violations.is_empty() => rsx! { div { "Legal build" } },
+      Some(Ok(violations)) => rsx! {
+        div { class: "subheading", "Build violations" }
+        for violation in violations {
+          div { class: "errored", "{violation}" }
+        }
+      },
+    }
+  }
+}
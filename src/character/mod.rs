@@ -2,8 +2,12 @@ mod aspects;
 mod attribute;
 mod fillable;
 mod components;
+mod controls;
 mod expertise;
 mod flow;
+mod inventory;
+mod layers;
+mod legality;
 mod resistance;
 mod sheet;
 
@@ -12,6 +16,12 @@ pub mod prelude {
   pub use super::attribute::{Capability, CharacterAttribute, Defense};
   pub use super::fillable::FillableSheet;
   pub use super::components::AttributeRow;
-  pub use super::resistance::{DamageClass, ResistanceDetails, Resistances};
+  pub use super::controls::SheetControls;
+  pub use super::expertise::ExpertiseEntry;
+  pub use super::flow::{Flow, FlowStat, ResourceStat};
+  pub use super::inventory::SheetInventory;
+  pub use super::layers::{AttributeLayer, SheetEnchantments, StatLayers};
+  pub use super::legality::SheetLegality;
+  pub use super::resistance::{DamageCategory, DamageClass, ResistanceDetails, Resistances};
   pub use super::sheet::{CharacterSheet, SheetDetails};
 }
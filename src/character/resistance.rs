@@ -124,6 +124,15 @@ impl Resistances {
     }
   }
 
+  pub fn add_block(&mut self, category: &DamageCategory, block: i32) {
+    let total = self.get_category(category) + block;
+    match category {
+      DamageCategory::Physical => self.physical = Some(total),
+      DamageCategory::Elemental => self.elemental = Some(total),
+      DamageCategory::Esoteric => self.esoteric = Some(total),
+    }
+  }
+
   pub fn get_category(&self, category: &DamageCategory) -> i32 {
     return self.category_ref(category).unwrap_or(BASE_RESIST);
   }
@@ -168,6 +177,26 @@ pub enum DamageCategory {
   Esoteric,
 }
 
+impl fmt::Display for DamageCategory {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        DamageCategory::Physical => "Physical",
+        DamageCategory::Elemental => "Elemental",
+        DamageCategory::Esoteric => "Esoteric",
+      }
+    )
+  }
+}
+
+impl DamageCategory {
+  pub fn ordered() -> Vec<DamageCategory> {
+    return vec![DamageCategory::Physical, DamageCategory::Elemental, DamageCategory::Esoteric];
+  }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 pub enum DamageClass {
   #[default]
@@ -209,6 +238,23 @@ impl fmt::Display for DamageClass {
 }
 
 impl DamageClass {
+  pub fn ordered() -> Vec<DamageClass> {
+    return vec![
+      DamageClass::Bashing,
+      DamageClass::Slashing,
+      DamageClass::Piercing,
+      DamageClass::Fire,
+      DamageClass::Cold,
+      DamageClass::Lighting,
+      DamageClass::Thunder,
+      DamageClass::Acid,
+      DamageClass::Force,
+      DamageClass::Radiant,
+      DamageClass::Necrotic,
+      DamageClass::Psionic,
+    ];
+  }
+
   fn category(&self) -> DamageCategory {
     match self {
       // Physical
@@ -1,4 +1,3 @@
-use std::cmp::max;
 use std::collections::HashSet;
 
 use bson::oid::ObjectId;
@@ -9,8 +8,12 @@ use crate::character::components::ConstitutionRow;
 use crate::character::expertise::ExpertiseComponent;
 use crate::character::flow::FlowResourcesBlock;
 // use crate::character::prelude::AttributeRow;
-use crate::common::{HorizontalBar, StaggeredCell, StaggeredGrid};
+use crate::common::{
+  EnumSelect, HorizontalBar, NumberField, OptionalNumberField, StaggeredCell, StaggeredGrid,
+};
 use crate::equipment::armor::{Armor, ArmorEntry};
+use crate::equipment::inventory::{self, EquipmentSlot, InventoryItem, Item};
+use crate::equipment::shield::{Shield, ShieldEntry};
 use crate::equipment::weapon::{Weapon, WeaponEntry};
 use crate::keyword::prelude::{KeywordCard, terms_and_conditions};
 use crate::path::components::{PathChipsLoader};
@@ -20,14 +23,21 @@ use crate::path::components::{PathChipsLoader};
 // use crate::skill::component::*;
 // use crate::skill::prelude::*;
 use crate::Route;
+use crate::progression::prelude::{
+  character_modifiers, derive_stats, stored_pool_ranks, DerivedStats, StatInputs, MAX_LEVEL,
+  MIN_LEVEL,
+};
+use crate::modifiers::prelude::*;
 use crate::rules::prelude::*;
-use crate::server::prelude::{KeywordCache, SkillCache};
+use crate::server::prelude::{sheet_with_catalog, KeywordCache, ResourceStatus, SkillCache};
 use crate::skill::component::SkillCardElements;
-use crate::skill::prelude::keywords_from_skills;
+use crate::skill::prelude::{keywords_from_skills, Skill};
 
+use super::attribute::CharacterAttribute;
 use super::aspects::{BodyStats, TrainingRanks};
 // use super::attribute::*;
 use super::expertise::ExpertiseEntry;
+use super::layers::{AttributeLayer, LayerBreakdown, StatLayers};
 use super::flow::FlowStat;
 use super::resistance::ResistanceDetails;
 use super::resistance::Resistances;
@@ -40,6 +50,7 @@ pub struct CharacterSheet {
   pub name: String,
   pub level: i32,
   pub attributes: AttributeRanks,
+  pub attribute_layers: Option<Vec<AttributeLayer>>,
   pub training: TrainingRanks,
   pub body: BodyStats,
   pub paths: HashSet<ObjectId>,
@@ -47,8 +58,200 @@ pub struct CharacterSheet {
   pub flows: Option<Vec<FlowStat>>,
   pub armor: Option<Armor>,
   pub weapons: Option<Vec<Weapon>>,
+  pub inventory: Option<Vec<InventoryItem>>,
   pub resistances: Option<Resistances>,
   pub expertise: Option<Vec<ExpertiseEntry>>,
+  pub revision: Option<i64>,
+}
+
+impl CharacterSheet {
+  pub fn validation_errors(&self) -> Vec<String> {
+    let mut errors: Vec<String> = Vec::new();
+    if self.name.trim().is_empty() {
+      errors.push("name is required".into());
+    }
+    if self.level < MIN_LEVEL || self.level > MAX_LEVEL {
+      errors.push(format!("level must be between {MIN_LEVEL} and {MAX_LEVEL}"));
+    }
+    if self.body.hp <= 0 {
+      errors.push("hp must be positive".into());
+    }
+    if self.body.constitution < 0 {
+      errors.push("constitution cannot be negative".into());
+    }
+    if self.body.speed < 0 {
+      errors.push("speed cannot be negative".into());
+    }
+    let attributes = self.attributes.list_capabilities();
+    let defenses = self.attributes.list_defenses();
+    for (attribute, rank) in attributes.iter().chain(defenses.iter()) {
+      if *rank < 0 {
+        errors.push(format!("{attribute} rank cannot be negative"));
+      }
+    }
+    for layer in self.attribute_layers.iter().flatten() {
+      let attribute = &layer.attribute;
+      if layer.layers.ranks < 0 || layer.layers.specialization < 0 {
+        errors.push(format!("{attribute} ranks and specialization cannot be negative"));
+      }
+      if layer.layers.specialization > 1 {
+        errors.push(format!("{attribute} can only be specialized once"));
+      }
+      if self.attributes.rank(attribute) != Some(layer.layers.total()) {
+        errors.push(format!(
+          "{attribute} does not match its ranks, specialization and enchantment"
+        ));
+      }
+    }
+    if let Some(flows) = &self.flows {
+      for flow in flows {
+        if flow.base < 0 || flow.pools.iter().any(|pool| pool.base < 0) {
+          errors.push(format!("{} flow cannot be negative", flow.flow));
+        }
+      }
+    }
+    if let Some(items) = &self.inventory {
+      errors.append(&mut inventory::validation_errors(items));
+    }
+    return errors;
+  }
+
+  pub fn attribute_layers(&self) -> Vec<AttributeLayer> {
+    if let Some(layers) = &self.attribute_layers {
+      return layers.clone();
+    }
+    return CharacterAttribute::ordered()
+      .into_iter()
+      .filter_map(|attribute| {
+        let ranks = self.attributes.rank(&attribute)?;
+        Some(AttributeLayer { attribute, layers: StatLayers { ranks, ..StatLayers::default() } })
+      })
+      .collect();
+  }
+
+  pub fn set_attribute_layers(&mut self, layers: Vec<AttributeLayer>) {
+    for layer in &layers {
+      self.attributes.set_rank(&layer.attribute, Some(layer.layers.total()));
+    }
+    self.attribute_layers = Some(layers);
+  }
+
+  pub fn inventory(&self) -> Vec<InventoryItem> {
+    if let Some(items) = &self.inventory {
+      return items.clone();
+    }
+    let mut items: Vec<InventoryItem> = Vec::new();
+    if let Some(armor) = &self.armor {
+      items.push(InventoryItem::equipped(Item::Armor(armor.clone()), EquipmentSlot::Armor));
+    }
+    let mut hands = vec![EquipmentSlot::MainHand, EquipmentSlot::OffHand].into_iter();
+    for weapon in self.weapons.clone().unwrap_or_default() {
+      let mut item = InventoryItem::new(Item::Weapon(weapon));
+      item.slot = hands.next();
+      items.push(item);
+    }
+    return items;
+  }
+
+  pub fn with_catalog(
+    &self, armor: impl Fn(&ObjectId) -> Option<Armor>,
+    weapon: impl Fn(&ObjectId) -> Option<Weapon>,
+  ) -> CharacterSheet {
+    let mut resolved = self.clone();
+    if let Some(items) = &self.inventory {
+      resolved.inventory = Some(inventory::resolve_items(items, armor, weapon));
+    }
+    return resolved;
+  }
+
+  pub fn set_inventory(&mut self, items: Vec<InventoryItem>) {
+    self.inventory = Some(items);
+    self.armor = None;
+    self.weapons = None;
+  }
+
+  pub fn equipped_armor(&self) -> Option<Armor> {
+    return inventory::equipped_armor(&self.inventory());
+  }
+
+  pub fn equipped_shield(&self) -> Option<Shield> {
+    return inventory::equipped_shield(&self.inventory());
+  }
+
+  pub fn equipped_weapons(&self) -> Vec<Weapon> {
+    if self.inventory.is_none() {
+      return self.weapons.clone().unwrap_or_default();
+    }
+    return inventory::equipped_weapons(&self.inventory());
+  }
+
+  pub fn derive(&self, skills: &[Skill]) -> DerivedStats {
+    return self.derive_with_gear(skills, &self.equipped_armor(), &self.equipped_shield());
+  }
+
+  pub fn derive_with_gear(
+    &self, skills: &[Skill], armor: &Option<Armor>, shield: &Option<Shield>,
+  ) -> DerivedStats {
+    let modifiers = character_modifiers(self.level, &self.training, skills);
+    return derive_stats(StatInputs {
+      level: self.level,
+      training: &self.training,
+      skills,
+      attributes: &self.attributes,
+      pool_ranks: stored_pool_ranks(&modifiers, &self.flows),
+      armor,
+      shield,
+      resistances: &self.resistances,
+    });
+  }
+
+  pub fn stat_discrepancies(&self, derived: &DerivedStats) -> Vec<String> {
+    let mut discrepancies: Vec<String> = Vec::new();
+    let speed = derived.modifiers.get(&ModifierClass::WalkingSpeed);
+    for (stat, stored, expected) in [
+      ("HP", self.body.hp, derived.body.hp),
+      ("constitution", self.body.constitution, derived.body.constitution),
+      ("speed", self.body.speed, speed),
+    ] {
+      if stored != expected {
+        discrepancies.push(format!("Stored {stat} {stored} but rules give {expected}"));
+      }
+    }
+    let stored_flows = self.flows.clone().unwrap_or_default();
+    let derived_flows = derived.flows.clone().unwrap_or_default();
+    for flow in &derived_flows {
+      let Some(stored_flow) = stored_flows.iter().find(|stored| stored.flow == flow.flow) else {
+        discrepancies.push(format!("Missing {} flow, rules give {}", flow.flow, flow.base));
+        continue;
+      };
+      if stored_flow.base != flow.base {
+        discrepancies.push(format!(
+          "Stored {} flow {} but rules give {}",
+          flow.flow, stored_flow.base, flow.base
+        ));
+      }
+      for pool in &flow.pools {
+        let stored_base = stored_flow
+          .pools
+          .iter()
+          .find(|stored| stored.resource == pool.resource)
+          .map(|stored| stored.base)
+          .unwrap_or_default();
+        if stored_base != pool.base {
+          discrepancies.push(format!(
+            "Stored {} pool {} but rules give {}",
+            pool.resource, stored_base, pool.base
+          ));
+        }
+      }
+    }
+    for stored_flow in &stored_flows {
+      if !derived_flows.iter().any(|flow| flow.flow == stored_flow.flow) {
+        discrepancies.push(format!("Stored {} flow is not granted by the rules", stored_flow.flow));
+      }
+    }
+    return discrepancies;
+  }
 }
 
 #[component]
@@ -66,61 +269,83 @@ pub fn SheetTable(
   )
 }
 
-fn adjust_for_armor( 
-  armor: &Option<Armor>,
-  mut attributes: AttributeRanks, 
-  resistances: Option<Resistances>, 
-  speed: i32,
-) -> (
-  AttributeRanks, Resistances, i32, i32
-) {
-  let mut base_resistances = resistances.unwrap_or_default();
-  let Some( equiped_armor ) = armor else {
-    attributes.update_dodge_with_bulk(0, 0);
-    return ( attributes, base_resistances, speed, 3); 
-  };
-  base_resistances.update_physical_resistance(equiped_armor.physical_resistance);
-  attributes.update_dodge_with_bulk(
-    equiped_armor.tenacity_requirement, 
-    equiped_armor.speed_penalty.unwrap_or_default()
-  );
-  let speed_penalty = equiped_armor.speed_penalty.unwrap_or_default();
-  let speed = max(speed - speed_penalty, 1);
-  let dash = max(3 - speed_penalty, 1);
-  return ( attributes, base_resistances, speed, dash); 
-}
-
 #[component]
 pub fn SheetDetails(
   sheet: CharacterSheet,
   #[props(default)] named_url: bool,
 ) -> Element {
+  let sheet = sheet_with_catalog(&sheet);
   let id = sheet.id.to_string();
-  let name = sheet.name;
-  let (attributes, resistances, speed, dash) = adjust_for_armor(
-    &sheet.armor,
-    sheet.attributes.clone(),
-    sheet.resistances.clone(),
-    sheet.body.speed,
-  );
-
-  let (armor_opt, armor_title) = match &sheet.armor {
-    Some( equiped_armor ) => (Some(equiped_armor.clone()), Some(equiped_armor.title.clone())),
-    None => (None, None),
+  let name = sheet.name.clone();
+  let SkillCache( ref skill_map ) = use_context();
+  let learned_skills = skill_map.from_object_ids(&sheet.skills);
+  let derived = sheet.derive(&learned_skills);
+  let discrepancies = match skill_map.status() {
+    ResourceStatus::Ready => sheet.stat_discrepancies(&derived),
+    _ => Vec::new(),
+  };
+  let DerivedStats { body, dash, attributes, resistances, flows: opt_flows, .. } = derived;
+  let attribute_memo = use_memo(use_reactive(&attributes, |attributes| attributes));
+  let mut odds_defense: Signal<Option<i32>> = use_signal(|| None);
+  let mut odds_mode: Signal<Modifier> = use_signal(|| Modifier::Normal);
+  let mut target_resistance: Signal<i32> = use_signal(|| 0);
+  use_context_provider(|| OddsContext {
+    attributes: attribute_memo,
+    defense: odds_defense,
+    mode: odds_mode,
+  });
+  let capability_odds: Vec<(String, RollOdds)> = match odds_defense() {
+    Some(defense) => attributes
+      .list_capabilities()
+      .into_iter()
+      .map(|(name, rank)| (name, roll_odds(rank, &odds_mode(), &RollClass::Attack, defense)))
+      .collect(),
+    None => Vec::new(),
   };
-  let body = sheet.body;
+  let speed = body.speed;
   let hp = body.hp;
+  let constitution = body.constitution;
+
+  let (armor_opt, armor_title) = match sheet.equipped_armor() {
+    Some( equiped_armor ) => (Some(equiped_armor.clone()), Some(equiped_armor.title)),
+    None => (None, None),
+  };
+  let mut capability_layers: Vec<(String, StatLayers)> = Vec::new();
+  let mut defense_layers: Vec<(String, StatLayers)> = Vec::new();
+  for AttributeLayer { attribute, layers } in sheet.attribute_layers() {
+    match attribute.is_capacity() {
+      true => capability_layers.push((attribute.to_string(), layers)),
+      false => defense_layers.push((attribute.to_string(), layers)),
+    }
+  }
+  let expertise_layers: Vec<(String, StatLayers)> = sheet
+    .expertise
+    .iter()
+    .flatten()
+    .filter_map(|entry| Some((entry.title.clone()?, entry.layers()?)))
+    .collect();
+  let weapons = sheet.equipped_weapons();
+  let shield = sheet.equipped_shield();
+  let shield_title = shield.as_ref().map(|shield| shield.title.clone());
+  let shield_opt = shield.filter(|shield| {
+    !weapons.iter().any(|weapon| Shield::from_weapon(weapon).as_ref() == Some(shield))
+  });
   let path_ids = sheet.paths;
-  let skill_ids = sheet.skills;
-  let SkillCache( ref skill_map ) = use_context();
+  let mut skill_ids: Vec<ObjectId> = Vec::new();
+  for skill_id in sheet.skills {
+    if !skill_ids.contains(&skill_id) {
+      skill_ids.push(skill_id);
+    }
+  }
   let skills = skill_map.from_object_ids(&skill_ids);
-  let keyword_id_objects = keywords_from_skills(&skills);
+  let mut keyword_id_objects = keywords_from_skills(&skills);
+  for weapon in &weapons {
+    keyword_id_objects.extend(weapon.keyword_ids.iter().flatten());
+  }
   let KeywordCache(ref keyword_cache) = use_context();
   let keywords_all = keyword_cache.from_object_set(&keyword_id_objects);
   let keywords = terms_and_conditions(keywords_all);
 
-  let opt_weapons = sheet.weapons;
-  let opt_flows = sheet.flows;
   let training = sheet.training;
   rsx! {
     div {
@@ -137,16 +362,51 @@ pub fn SheetDetails(
         span { class: "highlight", " Training" }
         " {training}"
       }
+      if !discrepancies.is_empty() {
+        div {
+          class: "uv-full column",
+          for discrepancy in discrepancies {
+            div { class: "errored", "{discrepancy}" }
+          }
+        }
+      }
+      div {
+        class: "uv-full row align-center no-print",
+        div { class: "highlight", "Odds against" }
+        OptionalNumberField {
+          value: odds_defense(),
+          onchange: move |value| odds_defense.set(value),
+        }
+        EnumSelect {
+          options: Modifier::ordered(),
+          selected: Some(odds_mode()),
+          onchange: move |value: Option<Modifier>| {
+            if let Some(value) = value {
+              odds_mode.set(value);
+            }
+          },
+        }
+        div { class: "highlight", "Resist" }
+        NumberField {
+          value: target_resistance(),
+          onchange: move |value| target_resistance.set(value),
+        }
+      }
       HorizontalBar {}
       div {
         class: "uv-capabilites column underhang",
         div { class: "subheading", "Capabilities" }
         CapabilityBlock { attributes: attributes.clone() }
+        LayerBreakdown { layers: capability_layers }
+        for (name, odds) in capability_odds {
+          div { class: "mini-text no-print", "{name}: {odds}" }
+        }
       }
       div {
         class: "uv-defenses column underhang",
         div { class: "subheading", "Defenses" }
         DefenseBlock { attributes: attributes.clone() }
+        LayerBreakdown { layers: defense_layers }
       }
       div {
         class: "uv-resistances column underhang",
@@ -157,6 +417,12 @@ pub fn SheetDetails(
             span { " {armor_title}" }
           }
         }
+        if let Some( shield_title ) = shield_title {
+          div {
+            span { class: "highlight", "Shield:" }
+            span { " {shield_title}" }
+          }
+        }
         ResistanceDetails { resistances }
       }
       div {
@@ -167,6 +433,7 @@ pub fn SheetDetails(
             ExpertiseComponent { entry }
           }
         }
+        LayerBreakdown { layers: expertise_layers }
       }
       div {
         class: "uv-resources column underhang",
@@ -174,20 +441,28 @@ pub fn SheetDetails(
         div { "Speed {speed} :: Dash {dash}" }
         div { "Health {hp}" }
         div { class: "hp-box" }
-        ConstitutionRow { constitution: 4 }
+        ConstitutionRow { constitution }
       }
       div {
         class: "uv-until-resources column underhang",
         div { class: "subheading underhang", "Equipment" }
           div { class: "staggered-grid-small", 
-          if let Some( weapons ) = opt_weapons {
-            for weapon in weapons {
-              StaggeredCell { WeaponEntry { weapon } }
+          for weapon in weapons {
+            StaggeredCell {
+              WeaponEntry { weapon: weapon.clone() }
+              DamageSummary {
+                group: weapon.damage_dice,
+                bonus: weapon.damage_modifier.unwrap_or_default(),
+                resistance: target_resistance(),
+              }
             }
           }
           if let Some( armor ) = armor_opt {
             StaggeredCell { ArmorEntry { armor } }
           }
+          if let Some( shield ) = shield_opt {
+            StaggeredCell { ShieldEntry { shield } }
+          }
         }
       }
       if let Some( flows ) = opt_flows {
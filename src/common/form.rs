@@ -0,0 +1,142 @@
+use std::fmt::Display;
+
+use dioxus::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SaveStatus {
+  Idle,
+  Saving,
+  Errored(String),
+}
+
+pub fn edit_with<S: Clone + 'static, T: 'static>(
+  value: &S, onchange: EventHandler<S>, apply: fn(&mut S, T),
+) -> impl FnMut(T) + 'static {
+  let value = value.clone();
+  move |input: T| {
+    let mut updated = value.clone();
+    apply(&mut updated, input);
+    onchange.call(updated);
+  }
+}
+
+#[component]
+pub fn FormRow(title: String, children: Element) -> Element {
+  rsx! {
+    div { class: "uv-title highlight", "{title}" }
+    div { class: "uv-details row align-center", {children} }
+  }
+}
+
+#[component]
+pub fn TextField(
+  value: String, onchange: EventHandler<String>, #[props(default)] multiline: bool,
+) -> Element {
+  rsx! {
+    if multiline {
+      textarea {
+        class: "input-text",
+        value,
+        oninput: move |event| onchange.call(event.value()),
+      }
+    } else {
+      input {
+        class: "input-text", r#type: "text",
+        value,
+        oninput: move |event| onchange.call(event.value()),
+      }
+    }
+  }
+}
+
+#[component]
+pub fn OptionalTextField(
+  value: Option<String>, onchange: EventHandler<Option<String>>, #[props(default)] multiline: bool,
+) -> Element {
+  rsx! {
+    TextField {
+      value: value.unwrap_or_default(),
+      multiline,
+      onchange: move |text: String| {
+        onchange.call(match text.is_empty() {
+          true => None,
+          false => Some(text),
+        })
+      },
+    }
+  }
+}
+
+#[component]
+pub fn NumberField(value: i32, onchange: EventHandler<i32>) -> Element {
+  rsx! {
+    input {
+      class: "input", r#type: "number",
+      value,
+      oninput: move |event| onchange.call(event.value().parse::<i32>().unwrap_or_default()),
+    }
+  }
+}
+
+#[component]
+pub fn OptionalNumberField(value: Option<i32>, onchange: EventHandler<Option<i32>>) -> Element {
+  let display = value.map(|number| number.to_string()).unwrap_or_default();
+  rsx! {
+    input {
+      class: "input", r#type: "number",
+      value: display,
+      oninput: move |event| onchange.call(event.value().parse::<i32>().ok()),
+    }
+  }
+}
+
+#[component]
+pub fn CheckboxField(checked: bool, onchange: EventHandler<bool>) -> Element {
+  rsx! {
+    input {
+      r#type: "checkbox",
+      checked,
+      oninput: move |_| onchange.call(!checked),
+    }
+  }
+}
+
+#[component]
+pub fn OptionalFlagField(value: Option<bool>, onchange: EventHandler<Option<bool>>) -> Element {
+  let checked = value.unwrap_or_default();
+  rsx! {
+    CheckboxField {
+      checked,
+      onchange: move |checked: bool| onchange.call(if checked { Some(true) } else { None }),
+    }
+  }
+}
+
+#[component]
+pub fn EnumSelect<T: Clone + PartialEq + Display + 'static>(
+  options: Vec<T>, selected: Option<T>, onchange: EventHandler<Option<T>>,
+  #[props(default)] optional: bool, #[props(default)] labels: Option<Vec<String>>,
+) -> Element {
+  let selected_index = selected
+    .as_ref()
+    .and_then(|value| options.iter().position(|option| option.eq(value)));
+  let labels = labels.unwrap_or(options.iter().map(|option| option.to_string()).collect());
+  rsx! {
+    select {
+      onchange: move |event| {
+        let value = event
+          .value()
+          .parse::<usize>()
+          .ok()
+          .and_then(|index| options.get(index).cloned());
+        onchange.call(value);
+      },
+      if optional || selected_index.is_none() {
+        option { value: "", selected: selected_index.is_none(), "None" }
+      }
+      for (index, label) in labels.into_iter().enumerate() {
+        option { value: "{index}", selected: selected_index == Some(index), "{label}" }
+      }
+    }
+  }
+}
@@ -1,7 +1,14 @@
+mod form;
+mod picker;
 mod sized;
 
 use dioxus::prelude::*;
 
+pub use form::{
+  edit_with, CheckboxField, EnumSelect, FormRow, NumberField, OptionalFlagField,
+  OptionalNumberField, OptionalTextField, SaveStatus, TextField,
+};
+pub use picker::{IdPicker, PickerItem};
 pub use sized::{StaggeredCell, StaggeredGrid};
 
 #[component]
@@ -0,0 +1,61 @@
+use bson::oid::ObjectId;
+use dioxus::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PickerItem {
+  pub id: ObjectId,
+  pub title: String,
+  pub label: String,
+}
+
+// Chips for the selected ids followed by a select that adds one of the remaining items.
+#[component]
+pub fn IdPicker(
+  items: Vec<PickerItem>, selected: Vec<ObjectId>, placeholder: String,
+  onchange: EventHandler<Vec<ObjectId>>,
+) -> Element {
+  let chips: Vec<(String, Vec<ObjectId>)> = selected
+    .iter()
+    .map(|id| {
+      let title = match items.iter().find(|item| &item.id == id) {
+        Some(item) => item.title.clone(),
+        None => format!("missing {id}"),
+      };
+      let remaining = selected.iter().filter(|other| *other != id).cloned().collect();
+      (title, remaining)
+    })
+    .collect();
+  let options: Vec<PickerItem> =
+    items.into_iter().filter(|item| !selected.contains(&item.id)).collect();
+  let labels: Vec<String> = options.iter().map(|item| item.label.clone()).collect();
+  rsx! {
+    div {
+      class: "row-wrap align-center gap",
+      for (title, remaining) in chips {
+        div {
+          class: "chip",
+          onclick: move |_| onchange.call(remaining.clone()),
+          "{title} ✕"
+        }
+      }
+      select {
+        onchange: move |event| {
+          let Some(item) = event
+            .value()
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| options.get(index)) else {
+            return;
+          };
+          let mut updated = selected.clone();
+          updated.push(item.id);
+          onchange.call(updated);
+        },
+        option { value: "", selected: true, "{placeholder}" }
+        for (index, label) in labels.into_iter().enumerate() {
+          option { value: "{index}", "{label}" }
+        }
+      }
+    }
+  }
+}
@@ -1,9 +1,12 @@
+use bson::oid::ObjectId;
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Armor {
+  #[serde(rename = "_id", default)]
+  pub id: ObjectId,
   pub title: String,
   pub physical_resistance: i32,
   pub tenacity_requirement: i32,
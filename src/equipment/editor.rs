@@ -0,0 +1,226 @@
+use dioxus::prelude::*;
+
+use super::armor::{Armor, ArmorEntry};
+use super::weapon::{Weapon, WeaponClass, WeaponEntry};
+use crate::character::prelude::DamageClass;
+use crate::common::*;
+use crate::keyword::prelude::*;
+use crate::rules::prelude::*;
+use crate::server::prelude::*;
+use crate::Route;
+
+#[component]
+pub fn WeaponEditor(weapon: Weapon, #[props(default)] is_new: bool) -> Element {
+  let WeaponCache(weapon_cache) = use_context::<WeaponCache>();
+  let KeywordCache(ref keyword_cache) = use_context::<KeywordCache>();
+  let mut draft: Signal<Weapon> = use_signal(|| weapon.clone());
+  let mut damage: Signal<String> = use_signal(|| weapon.damage().to_string());
+  let mut status: Signal<SaveStatus> = use_signal(|| SaveStatus::Idle);
+  let nav = navigator();
+  let current = draft();
+  let damage_error = damage().parse::<DiceExpression>().err();
+  let valid = !current.title.trim().is_empty()
+    && damage_error.is_none()
+    && current.damage_dice.iter().all(|dice| dice.amount > 0);
+  let saving = status() == SaveStatus::Saving;
+  let save_class = if saving || !valid { "disabled" } else { "" };
+  let keyword_ids = current.keyword_ids.clone().unwrap_or_default();
+  let properties = keyword_cache.from_object_ids(&keyword_ids);
+  rsx! {
+    div {
+      class: "row gap-large align-start",
+      div {
+        class: "card grid dim-keywords no-print",
+        FormRow {
+          title: "Title",
+          TextField {
+            value: current.title.clone(),
+            onchange: move |value| draft.write().title = value,
+          }
+        }
+        FormRow {
+          title: "Class",
+          EnumSelect {
+            options: WeaponClass::ordered(),
+            selected: Some(current.class.clone()),
+            onchange: move |value: Option<WeaponClass>| {
+              if let Some(class) = value {
+                draft.write().class = class;
+              }
+            },
+          }
+        }
+        FormRow {
+          title: "Damage",
+          EnumSelect {
+            options: DamageClass::ordered(),
+            selected: Some(current.damage_class.clone()),
+            onchange: move |value: Option<DamageClass>| {
+              if let Some(class) = value {
+                draft.write().damage_class = class;
+              }
+            },
+          }
+          TextField {
+            value: damage(),
+            onchange: move |value: String| {
+              if let Ok(expression) = value.parse::<DiceExpression>() {
+                draft.write().set_damage(expression);
+              }
+              damage.set(value);
+            },
+          }
+          if let Some(error) = damage_error {
+            div { class: "errored", "{error}" }
+          }
+        }
+        FormRow {
+          title: "Range",
+          OptionalNumberField {
+            value: current.range,
+            onchange: move |value| draft.write().range = value,
+          }
+        }
+        FormRow {
+          title: "Block",
+          OptionalNumberField {
+            value: current.block,
+            onchange: move |value| draft.write().block = value,
+          }
+        }
+        FormRow {
+          title: "Properties",
+          KeywordPicker {
+            selected: keyword_ids,
+            onchange: move |ids: Vec<_>| {
+              draft.write().keyword_ids = if ids.is_empty() { None } else { Some(ids) };
+            },
+          }
+        }
+        div {
+          class: "uv-full row gap-large spacer",
+          div {
+            class: "next-button {save_class}",
+            onclick: move |_| {
+              if saving || !valid {
+                return;
+              }
+              let weapon = draft();
+              let cache = weapon_cache.clone();
+              status.set(SaveStatus::Saving);
+              spawn(async move {
+                let result = match is_new {
+                  true => insert_weapon(weapon).await,
+                  false => update_weapon(weapon).await,
+                };
+                match result {
+                  Ok(_) => {
+                    status.set(SaveStatus::Idle);
+                    cache.restart();
+                    nav.push(Route::EquipmentPage {});
+                  },
+                  Err(error) => status.set(SaveStatus::Errored(error.to_string())),
+                }
+              });
+            },
+            if saving { "Saving ..." } else { "Save weapon" }
+          }
+        }
+        if let SaveStatus::Errored(error) = status() {
+          div { class: "uv-full errored", "Unable to save weapon: {error}" }
+        }
+      }
+      div {
+        class: "column",
+        WeaponEntry { weapon: current }
+        KeywordCards { keywords: properties }
+      }
+    }
+  }
+}
+
+#[component]
+pub fn ArmorEditor(armor: Armor, #[props(default)] is_new: bool) -> Element {
+  let ArmorCache(armor_cache) = use_context::<ArmorCache>();
+  let mut draft: Signal<Armor> = use_signal(|| armor.clone());
+  let mut status: Signal<SaveStatus> = use_signal(|| SaveStatus::Idle);
+  let nav = navigator();
+  let current = draft();
+  let valid = !current.title.trim().is_empty()
+    && current.physical_resistance >= 0
+    && current.tenacity_requirement >= 0;
+  let saving = status() == SaveStatus::Saving;
+  let save_class = if saving || !valid { "disabled" } else { "" };
+  rsx! {
+    div {
+      class: "row gap-large align-start",
+      div {
+        class: "card grid dim-keywords no-print",
+        FormRow {
+          title: "Title",
+          TextField {
+            value: current.title.clone(),
+            onchange: move |value| draft.write().title = value,
+          }
+        }
+        FormRow {
+          title: "Armor",
+          NumberField {
+            value: current.physical_resistance,
+            onchange: move |value| draft.write().physical_resistance = value,
+          }
+        }
+        FormRow {
+          title: "Bulk",
+          NumberField {
+            value: current.tenacity_requirement,
+            onchange: move |value| draft.write().tenacity_requirement = value,
+          }
+        }
+        FormRow {
+          title: "Drag",
+          OptionalNumberField {
+            value: current.speed_penalty,
+            onchange: move |value| draft.write().speed_penalty = value,
+          }
+        }
+        div {
+          class: "uv-full row gap-large spacer",
+          div {
+            class: "next-button {save_class}",
+            onclick: move |_| {
+              if saving || !valid {
+                return;
+              }
+              let armor = draft();
+              let cache = armor_cache.clone();
+              status.set(SaveStatus::Saving);
+              spawn(async move {
+                let result = match is_new {
+                  true => insert_armor(armor).await,
+                  false => update_armor(armor).await,
+                };
+                match result {
+                  Ok(_) => {
+                    status.set(SaveStatus::Idle);
+                    cache.restart();
+                    nav.push(Route::EquipmentPage {});
+                  },
+                  Err(error) => status.set(SaveStatus::Errored(error.to_string())),
+                }
+              });
+            },
+            if saving { "Saving ..." } else { "Save armor" }
+          }
+        }
+        if let SaveStatus::Errored(error) = status() {
+          div { class: "uv-full errored", "Unable to save armor: {error}" }
+        }
+      }
+      div {
+        class: "column",
+        ArmorEntry { armor: current }
+      }
+    }
+  }
+}
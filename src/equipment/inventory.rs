@@ -0,0 +1,232 @@
+use bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::armor::Armor;
+use super::shield::Shield;
+use super::weapon::Weapon;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum EquipmentSlot {
+  Armor,
+  MainHand,
+  OffHand,
+  Shield,
+}
+
+impl fmt::Display for EquipmentSlot {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        EquipmentSlot::Armor => "Armor",
+        EquipmentSlot::MainHand => "Main Hand",
+        EquipmentSlot::OffHand => "Off Hand",
+        EquipmentSlot::Shield => "Shield",
+      }
+    )
+  }
+}
+
+impl EquipmentSlot {
+  pub fn ordered() -> Vec<EquipmentSlot> {
+    return vec![
+      EquipmentSlot::Armor,
+      EquipmentSlot::MainHand,
+      EquipmentSlot::OffHand,
+      EquipmentSlot::Shield,
+    ];
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum Item {
+  Gear(String),
+  Armor(Armor),
+  Weapon(Weapon),
+  Shield(Shield),
+  CatalogArmor(ObjectId),
+  CatalogWeapon(ObjectId),
+}
+
+impl Item {
+  pub fn title(&self) -> String {
+    return match self {
+      Item::Gear(title) => title.clone(),
+      Item::Armor(armor) => armor.title.clone(),
+      Item::Weapon(weapon) => weapon.title.clone(),
+      Item::Shield(shield) => shield.title.clone(),
+      Item::CatalogArmor(id) => format!("Missing catalog armor {id}"),
+      Item::CatalogWeapon(id) => format!("Missing catalog weapon {id}"),
+    };
+  }
+
+  pub fn fits(&self, slot: &EquipmentSlot) -> bool {
+    return match (self, slot) {
+      (Item::Armor(_) | Item::CatalogArmor(_), EquipmentSlot::Armor) => true,
+      (Item::Weapon(_), EquipmentSlot::MainHand | EquipmentSlot::OffHand) => true,
+      (Item::Weapon(weapon), EquipmentSlot::Shield) => weapon.block.is_some(),
+      (Item::Shield(_), EquipmentSlot::Shield) => true,
+      (Item::CatalogWeapon(_), EquipmentSlot::MainHand | EquipmentSlot::OffHand) => true,
+      _ => false,
+    };
+  }
+
+  pub fn slots(&self) -> Vec<EquipmentSlot> {
+    return EquipmentSlot::ordered().into_iter().filter(|slot| self.fits(slot)).collect();
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryItem {
+  pub id: ObjectId,
+  pub item: Item,
+  pub quantity: i32,
+  pub notes: Option<String>,
+  pub slot: Option<EquipmentSlot>,
+}
+
+impl InventoryItem {
+  pub fn new(item: Item) -> Self {
+    return Self { id: ObjectId::new(), item, quantity: 1, notes: None, slot: None };
+  }
+
+  pub fn equipped(item: Item, slot: EquipmentSlot) -> Self {
+    return Self { slot: Some(slot), ..Self::new(item) };
+  }
+}
+
+pub fn resolve_items(
+  items: &[InventoryItem], armor: impl Fn(&ObjectId) -> Option<Armor>,
+  weapon: impl Fn(&ObjectId) -> Option<Weapon>,
+) -> Vec<InventoryItem> {
+  return items
+    .iter()
+    .map(|entry| {
+      let resolved = match &entry.item {
+        Item::CatalogArmor(id) => armor(id).map(Item::Armor),
+        Item::CatalogWeapon(id) => weapon(id).map(Item::Weapon),
+        _ => None,
+      };
+      match resolved {
+        Some(item) => InventoryItem { item, ..entry.clone() },
+        None => entry.clone(),
+      }
+    })
+    .collect();
+}
+
+// Catalog entries only know their slots once resolved, so fit is checked against `resolved`.
+pub fn equip(
+  items: &mut [InventoryItem], resolved: &[InventoryItem], id: &ObjectId, slot: EquipmentSlot,
+) -> Result<(), String> {
+  let Some(index) = items.iter().position(|entry| &entry.id == id) else {
+    return Err("Item is not in the inventory".into());
+  };
+  let entry = resolved.iter().find(|entry| &entry.id == id).unwrap_or(&items[index]);
+  if !entry.item.fits(&slot) {
+    return Err(format!("{} cannot be equipped as {slot}", entry.item.title()));
+  }
+  if entry.quantity < 1 {
+    return Err(format!("No {} left to equip", entry.item.title()));
+  }
+  for other in items.iter_mut() {
+    if other.slot.as_ref() == Some(&slot) {
+      other.slot = None;
+    }
+  }
+  items[index].slot = Some(slot);
+  return Ok(());
+}
+
+pub fn unequip(items: &mut [InventoryItem], id: &ObjectId) {
+  for entry in items.iter_mut().filter(|entry| &entry.id == id) {
+    entry.slot = None;
+  }
+}
+
+pub fn equipped_armor(items: &[InventoryItem]) -> Option<Armor> {
+  return items.iter().find_map(|entry| match (&entry.item, &entry.slot) {
+    (Item::Armor(armor), Some(EquipmentSlot::Armor)) => Some(armor.clone()),
+    _ => None,
+  });
+}
+
+pub fn equipped_shield(items: &[InventoryItem]) -> Option<Shield> {
+  return items.iter().find_map(|entry| match (&entry.item, &entry.slot) {
+    (Item::Shield(shield), Some(EquipmentSlot::Shield)) => Some(shield.clone()),
+    (Item::Weapon(weapon), Some(EquipmentSlot::Shield)) => Shield::from_weapon(weapon),
+    _ => None,
+  });
+}
+
+pub fn equipped_weapons(items: &[InventoryItem]) -> Vec<Weapon> {
+  return EquipmentSlot::ordered()
+    .iter()
+    .filter_map(|slot| {
+      items.iter().find_map(|entry| match &entry.item {
+        Item::Weapon(weapon) if entry.slot.as_ref() == Some(slot) => Some(weapon.clone()),
+        _ => None,
+      })
+    })
+    .collect();
+}
+
+pub fn validation_errors(items: &[InventoryItem]) -> Vec<String> {
+  let mut errors: Vec<String> = Vec::new();
+  for entry in items {
+    let title = entry.item.title();
+    if title.trim().is_empty() {
+      errors.push("inventory items need a title".into());
+    }
+    if entry.quantity < 0 {
+      errors.push(format!("{title} quantity cannot be negative"));
+    }
+    if let Item::Shield(shield) = &entry.item {
+      if shield.block < 0 || shield.tenacity_requirement < 0 {
+        errors.push(format!("{title} block and bulk cannot be negative"));
+      }
+    }
+    if let Some(slot) = &entry.slot {
+      if !entry.item.fits(slot) {
+        errors.push(format!("{title} cannot be equipped as {slot}"));
+      }
+    }
+  }
+  for slot in EquipmentSlot::ordered() {
+    if items.iter().filter(|entry| entry.slot.as_ref() == Some(&slot)).count() > 1 {
+      errors.push(format!("only one item can be equipped as {slot}"));
+    }
+  }
+  return errors;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn buckler() -> Weapon {
+    return Weapon { title: "Buckler".into(), block: Some(1), ..Weapon::default() };
+  }
+
+  #[test]
+  fn unresolved_catalog_weapons_only_fit_hands() {
+    let item = Item::CatalogWeapon(ObjectId::new());
+    assert_eq!(item.slots(), vec![EquipmentSlot::MainHand, EquipmentSlot::OffHand]);
+  }
+
+  #[test]
+  fn resolved_catalog_weapons_can_block() {
+    let weapon = buckler();
+    let mut items = vec![InventoryItem::new(Item::CatalogWeapon(weapon.id))];
+    let id = items[0].id;
+    assert!(equip(&mut items.clone(), &[], &id, EquipmentSlot::Shield).is_err());
+    let resolved = resolve_items(&items, |_| None, |_| Some(weapon.clone()));
+    assert_eq!(equip(&mut items, &resolved, &id, EquipmentSlot::Shield), Ok(()));
+    assert_eq!(items[0].item, Item::CatalogWeapon(weapon.id));
+    assert!(!validation_errors(&items).is_empty());
+    assert!(validation_errors(&resolve_items(&items, |_| None, |_| Some(buckler()))).is_empty());
+  }
+}
@@ -1,2 +1,5 @@
 pub mod armor;
+pub mod editor;
+pub mod inventory;
+pub mod shield;
 pub mod weapon;
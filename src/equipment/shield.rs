@@ -0,0 +1,59 @@
+use bson::oid::ObjectId;
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::weapon::Weapon;
+use crate::character::prelude::DamageCategory;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Shield {
+  #[serde(rename = "_id", default)]
+  pub id: ObjectId,
+  pub title: String,
+  pub block: i32,
+  pub categories: Vec<DamageCategory>,
+  pub tenacity_requirement: i32,
+}
+
+impl Default for Shield {
+  fn default() -> Self {
+    return Self {
+      id: ObjectId::default(),
+      title: String::new(),
+      block: 1,
+      categories: vec![DamageCategory::Physical],
+      tenacity_requirement: 0,
+    };
+  }
+}
+
+impl Shield {
+  pub fn from_weapon(weapon: &Weapon) -> Option<Shield> {
+    let block = weapon.block?;
+    return Some(Shield { id: weapon.id, title: weapon.title.clone(), block, ..Shield::default() });
+  }
+
+  pub fn blocks(&self) -> String {
+    let categories: Vec<String> =
+      self.categories.iter().map(|category| category.to_string()).collect();
+    return format!("Block {} {}", self.block, categories.join(", "));
+  }
+}
+
+#[component]
+pub fn ShieldEntry(shield: Shield) -> Element {
+  let blocks = shield.blocks();
+  let tenacity_requirement =
+    if shield.tenacity_requirement <= 0 { None } else { Some(shield.tenacity_requirement) };
+  rsx! {
+    div {
+      class: "card-snug column",
+      div { class: "underline highlight", "{shield.title}" }
+      div { "{blocks}" }
+      if let Some(tenacity_requirement) = tenacity_requirement {
+        div { "Bulk {tenacity_requirement}" }
+      }
+    }
+  }
+}
@@ -1,19 +1,37 @@
+use bson::oid::ObjectId;
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use crate::server::prelude::KeywordCache;
 use crate::{character::prelude::DamageClass, rules::prelude::*};
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Weapon {
+  #[serde(rename = "_id", default)]
+  pub id: ObjectId,
   pub title: String,
   pub class: WeaponClass,
   pub damage_dice: DiceGroup,
+  pub damage_modifier: Option<i32>,
   pub damage_class: DamageClass,
   pub range: Option<i32>,
   pub block: Option<i32>,
   pub keywords: Option<String>,
+  pub keyword_ids: Option<Vec<ObjectId>>,
+}
+
+impl Weapon {
+  pub fn damage(&self) -> DiceExpression {
+    let modifier = self.damage_modifier.unwrap_or_default();
+    return DiceExpression::new(self.damage_dice.clone(), modifier);
+  }
+
+  pub fn set_damage(&mut self, damage: DiceExpression) {
+    self.damage_dice = damage.dice;
+    self.damage_modifier = if damage.modifier == 0 { None } else { Some(damage.modifier) };
+  }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
@@ -24,6 +42,12 @@ pub enum WeaponClass {
   Ranged,
 }
 
+impl WeaponClass {
+  pub fn ordered() -> Vec<WeaponClass> {
+    return vec![WeaponClass::Melee, WeaponClass::Thrown, WeaponClass::Ranged];
+  }
+}
+
 impl fmt::Display for WeaponClass {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
     write!(
@@ -42,11 +66,21 @@ impl fmt::Display for WeaponClass {
 pub fn WeaponEntry(
   weapon: ReadSignal<Weapon>,
 ) -> Element {
+  let KeywordCache(ref keyword_cache) = use_context();
+  let table = try_use_context::<TableRollContext>();
   let weapon_signal = &weapon.read();
   let title = &weapon_signal.title;
   let weapon_class = &weapon_signal.class;
-  let opt_keywords = &weapon_signal.keywords;
-  let damage_dice = weapon_signal.damage_dice.clone();
+  let keyword_ids = weapon_signal.keyword_ids.clone().unwrap_or_default();
+  let linked: Vec<String> =
+    keyword_cache.from_object_ids(&keyword_ids).into_iter().map(|keyword| keyword.title).collect();
+  let opt_keywords = match linked.is_empty() {
+    true => weapon_signal.keywords.clone(),
+    false => Some(linked.join(", ")),
+  };
+  let damage = weapon_signal.damage();
+  let damage_dice = damage.dice;
+  let damage_modifier = damage.modifier;
   let damage_class = &weapon_signal.damage_class;
   let opt_range = weapon_signal.range;
   let opt_block = weapon_signal.block;
@@ -54,8 +88,17 @@ pub fn WeaponEntry(
     div {
       class: "card-snug column",
       div { class: "underline highlight", "{title}" }
+      if let Some(table) = table {
+        div {
+          class: "chip no-print",
+          onclick: move |_| {
+            table.on_roll.call(TableRoll::Attack { weapon_id: weapon.read().id });
+          },
+          "Attack"
+        }
+      }
       div {
-        DiceGroupEntry { group: damage_dice }
+        DiceGroupEntry { group: damage_dice, modifier: damage_modifier }
         " {damage_class}"
       }
       if let Some( block ) = opt_block {
@@ -0,0 +1,322 @@
+use std::collections::{HashMap, HashSet};
+
+use bson::oid::ObjectId;
+
+use super::{IntegrityIssue, IntegrityReport, IssueKind};
+use crate::character::prelude::CharacterSheet;
+use crate::equipment::armor::Armor;
+use crate::equipment::inventory::Item;
+use crate::equipment::weapon::Weapon;
+use crate::keyword::prelude::*;
+use crate::path::prelude::*;
+use crate::skill::prelude::*;
+
+const SKILLS: &str = "skills_display";
+const PATHS: &str = "paths_display";
+const KEYWORDS: &str = "keywords_display";
+const WEAPONS: &str = "weapons";
+const ARMOR: &str = "armor";
+const CREATURES: &str = "creatures";
+
+struct Source<'a> {
+  collection: &'a str,
+  id: ObjectId,
+  title: &'a str,
+}
+
+impl Source<'_> {
+  fn issue(
+    &self, kind: IssueKind, field: &str, target_collection: &str, target_id: &ObjectId,
+  ) -> IntegrityIssue {
+    return IntegrityIssue {
+      kind,
+      collection: self.collection.into(),
+      id: self.id.to_hex(),
+      title: self.title.into(),
+      field: field.into(),
+      target_collection: target_collection.into(),
+      target_id: target_id.to_hex(),
+    };
+  }
+}
+
+fn dangling<'a, T>(
+  issues: &mut Vec<IntegrityIssue>, source: &Source, field: &str, target_collection: &str,
+  targets: &HashMap<String, T>, ids: impl IntoIterator<Item = &'a ObjectId>,
+) {
+  let mut reported = HashSet::new();
+  for id in ids {
+    if !targets.contains_key(&id.to_hex()) && reported.insert(*id) {
+      issues.push(source.issue(IssueKind::Dangling, field, target_collection, id));
+    }
+  }
+}
+
+fn action_keyword_ids(skill: &Skill) -> HashSet<ObjectId> {
+  let mut ids = HashSet::new();
+  for action in std::iter::once(&skill.action).chain(skill.sub_actions.iter().flatten()) {
+    ids.extend(action.keyword_ids.clone().unwrap_or_default());
+  }
+  return ids;
+}
+
+pub fn check_integrity(
+  skills: &HashMap<String, Skill>, paths: &HashMap<String, Path>,
+  keywords: &HashMap<String, Keyword>, weapons: &HashMap<String, Weapon>,
+  armor: &HashMap<String, Armor>, sheets: &HashMap<String, CharacterSheet>,
+) -> IntegrityReport {
+  let mut issues = Vec::new();
+  for skill in skills.values() {
+    let source = Source { collection: SKILLS, id: skill.id, title: &skill.title };
+    let skill_paths = skill.paths.clone().unwrap_or_default();
+    dangling(&mut issues, &source, "paths", PATHS, paths, skill_paths.iter());
+    for path_id in skill_paths.iter() {
+      let Some(path) = paths.get(&path_id.to_hex()) else {
+        continue;
+      };
+      if !path.skill_ids.iter().flatten().any(|id| *id == skill.id) {
+        issues.push(source.issue(IssueKind::OneWay, "paths", PATHS, path_id));
+      }
+    }
+    let action_ids = action_keyword_ids(skill);
+    dangling(&mut issues, &source, "action keywords", KEYWORDS, keywords, action_ids.iter());
+    let term_ids = skill.get_keyword_ids();
+    let term_ids = term_ids.difference(&action_ids);
+    dangling(&mut issues, &source, "rules terms", KEYWORDS, keywords, term_ids);
+  }
+  for path in paths.values() {
+    let source = Source { collection: PATHS, id: path.id, title: &path.title };
+    let skill_ids = path.skill_ids.clone().unwrap_or_default();
+    dangling(&mut issues, &source, "skill ids", SKILLS, skills, skill_ids.iter());
+    for skill_id in skill_ids.iter() {
+      let Some(skill) = skills.get(&skill_id.to_hex()) else {
+        continue;
+      };
+      if !skill.paths.iter().flatten().any(|id| *id == path.id) {
+        issues.push(source.issue(IssueKind::OneWay, "skill ids", SKILLS, skill_id));
+      }
+    }
+  }
+  for keyword in keywords.values() {
+    let source = Source { collection: KEYWORDS, id: keyword.id, title: &keyword.title };
+    let term_ids: HashSet<ObjectId> = keyword
+      .rules
+      .iter()
+      .flatten()
+      .flat_map(|snippet| snippet.get_keyword_ids())
+      .collect();
+    dangling(&mut issues, &source, "rules terms", KEYWORDS, keywords, term_ids.iter());
+  }
+  for weapon in weapons.values() {
+    let source = Source { collection: WEAPONS, id: weapon.id, title: &weapon.title };
+    let keyword_ids = weapon.keyword_ids.iter().flatten();
+    dangling(&mut issues, &source, "keyword ids", KEYWORDS, keywords, keyword_ids);
+  }
+  for sheet in sheets.values() {
+    let source = Source { collection: CREATURES, id: sheet.id, title: &sheet.name };
+    dangling(&mut issues, &source, "skills", SKILLS, skills, sheet.skills.iter());
+    dangling(&mut issues, &source, "paths", PATHS, paths, sheet.paths.iter());
+    let items: Vec<&Item> = sheet.inventory.iter().flatten().map(|entry| &entry.item).collect();
+    let armor_ids = items.iter().filter_map(|item| match item {
+      Item::CatalogArmor(id) => Some(id),
+      _ => None,
+    });
+    dangling(&mut issues, &source, "inventory", ARMOR, armor, armor_ids);
+    let weapon_ids = items.iter().filter_map(|item| match item {
+      Item::CatalogWeapon(id) => Some(id),
+      _ => None,
+    });
+    dangling(&mut issues, &source, "inventory", WEAPONS, weapons, weapon_ids);
+  }
+  issues.sort();
+  return IntegrityReport {
+    documents: skills.len()
+      + paths.len()
+      + keywords.len()
+      + weapons.len()
+      + armor.len()
+      + sheets.len(),
+    issues,
+  };
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::character::prelude::{BodyStats, TrainingRanks};
+  use crate::equipment::inventory::InventoryItem;
+  use crate::rules::prelude::{rule_sections_from_block, AttributeRanks, Snippet, Tier};
+
+  fn sheet(inventory: Vec<Item>) -> CharacterSheet {
+    return CharacterSheet {
+      id: ObjectId::new(),
+      name: "Tester".into(),
+      level: 1,
+      attributes: AttributeRanks::default(),
+      attribute_layers: None,
+      training: TrainingRanks::default(),
+      body: BodyStats { hp: 30, constitution: 4, speed: 6 },
+      paths: HashSet::new(),
+      skills: Vec::new(),
+      flows: None,
+      armor: None,
+      weapons: None,
+      inventory: Some(inventory.into_iter().map(InventoryItem::new).collect()),
+      resistances: None,
+      expertise: None,
+      revision: None,
+    };
+  }
+
+  fn by_id<T>(id: ObjectId, value: T) -> HashMap<String, T> {
+    return HashMap::from([(id.to_hex(), value)]);
+  }
+
+  fn path(skill_ids: Vec<ObjectId>) -> Path {
+    return Path {
+      id: ObjectId::new(),
+      tier: Tier::Initiate,
+      title: "Warden".into(),
+      summary: None,
+      skill_ids: Some(skill_ids),
+      inherient: None,
+      order: None,
+      selections: None,
+    };
+  }
+
+  fn term(keyword_id: ObjectId) -> Snippet {
+    let term = Term { keyword_id: Some(keyword_id), ..Term::default() };
+    return Snippet { term: Some(term), ..Snippet::default() };
+  }
+
+  fn issues(report: &IntegrityReport) -> Vec<(IssueKind, &str, &str, &str)> {
+    return report
+      .issues
+      .iter()
+      .map(|issue| {
+        (issue.kind.clone(), issue.title.as_str(), issue.field.as_str(), issue.target_id.as_str())
+      })
+      .collect();
+  }
+
+  fn targets(report: &IntegrityReport) -> Vec<(&str, &str)> {
+    return report
+      .issues
+      .iter()
+      .map(|issue| (issue.field.as_str(), issue.target_collection.as_str()))
+      .collect();
+  }
+
+  #[test]
+  fn reports_dangling_weapon_keywords() {
+    let weapon = Weapon { keyword_ids: Some(vec![ObjectId::new()]), ..Weapon::default() };
+    let weapons = by_id(weapon.id, weapon);
+    let report = check_integrity(
+      &HashMap::new(),
+      &HashMap::new(),
+      &HashMap::new(),
+      &weapons,
+      &HashMap::new(),
+      &HashMap::new(),
+    );
+    assert_eq!(targets(&report), vec![("keyword ids", KEYWORDS)]);
+    assert_eq!(report.documents, 1);
+  }
+
+  #[test]
+  fn reports_dangling_catalog_items() {
+    let armor = Armor { id: ObjectId::new(), ..Armor::default() };
+    let armors = by_id(armor.id, armor.clone());
+    let items = vec![
+      Item::CatalogArmor(armor.id),
+      Item::CatalogArmor(ObjectId::new()),
+      Item::CatalogWeapon(ObjectId::new()),
+      Item::Gear("Rope".into()),
+    ];
+    let character = sheet(items);
+    let sheets = by_id(character.id, character);
+    let report = check_integrity(
+      &HashMap::new(),
+      &HashMap::new(),
+      &HashMap::new(),
+      &HashMap::new(),
+      &armors,
+      &sheets,
+    );
+    assert_eq!(targets(&report), vec![("inventory", ARMOR), ("inventory", WEAPONS)]);
+  }
+
+  #[test]
+  fn reports_dangling_and_one_way_path_links() {
+    let (missing_path, missing_skill) = (ObjectId::new(), ObjectId::new());
+    let mut skill = Skill { title: "Parry".into(), ..Skill::default() };
+    let listed = Path { title: "Listed".into(), ..path(vec![skill.id, missing_skill]) };
+    let unlisted = Path { title: "Unlisted".into(), ..path(Vec::new()) };
+    let claimed = Path { title: "Claimed".into(), ..path(vec![skill.id]) };
+    skill.paths = Some([listed.id, unlisted.id, missing_path].into());
+    let paths: HashMap<String, Path> = [&listed, &unlisted, &claimed]
+      .into_iter()
+      .map(|path| (path.id.to_hex(), path.clone()))
+      .collect();
+    let report = check_integrity(
+      &by_id(skill.id, skill.clone()),
+      &paths,
+      &HashMap::new(),
+      &HashMap::new(),
+      &HashMap::new(),
+      &HashMap::new(),
+    );
+    let (missing_path, missing_skill) = (missing_path.to_hex(), missing_skill.to_hex());
+    let (unlisted_id, skill_id) = (unlisted.id.to_hex(), skill.id.to_hex());
+    let mut expected = vec![
+      (IssueKind::Dangling, "Parry", "paths", missing_path.as_str()),
+      (IssueKind::Dangling, "Listed", "skill ids", missing_skill.as_str()),
+      (IssueKind::OneWay, "Parry", "paths", unlisted_id.as_str()),
+      (IssueKind::OneWay, "Claimed", "skill ids", skill_id.as_str()),
+    ];
+    let mut found = issues(&report);
+    expected.sort();
+    found.sort();
+    assert_eq!(found, expected);
+    assert_eq!(report.documents, 4);
+  }
+
+  #[test]
+  fn reports_dangling_action_keywords_and_terms() {
+    let known = Keyword { id: ObjectId::new(), ..Keyword::default() };
+    let (action_keyword, skill_term, keyword_term) =
+      (ObjectId::new(), ObjectId::new(), ObjectId::new());
+    let mut skill = Skill { title: "Parry".into(), ..Skill::default() };
+    skill.action.keyword_ids = Some(vec![known.id, action_keyword]);
+    skill.action.condition = Some(rule_sections_from_block(vec![term(known.id), term(skill_term)]));
+    let rules_keyword = Keyword {
+      id: ObjectId::new(),
+      title: "Burning".into(),
+      rules: Some(vec![term(keyword_term)]),
+      ..Keyword::default()
+    };
+    let keywords: HashMap<String, Keyword> = [&known, &rules_keyword]
+      .into_iter()
+      .map(|keyword| (keyword.id.to_hex(), keyword.clone()))
+      .collect();
+    let report = check_integrity(
+      &by_id(skill.id, skill.clone()),
+      &HashMap::new(),
+      &keywords,
+      &HashMap::new(),
+      &HashMap::new(),
+      &HashMap::new(),
+    );
+    let ids = [action_keyword.to_hex(), skill_term.to_hex(), keyword_term.to_hex()];
+    let mut expected = vec![
+      (IssueKind::Dangling, "Parry", "action keywords", ids[0].as_str()),
+      (IssueKind::Dangling, "Parry", "rules terms", ids[1].as_str()),
+      (IssueKind::Dangling, "Burning", "rules terms", ids[2].as_str()),
+    ];
+    let mut found = issues(&report);
+    expected.sort();
+    found.sort();
+    assert_eq!(found, expected);
+  }
+}
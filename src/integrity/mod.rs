@@ -0,0 +1,65 @@
+#[cfg(any(feature = "server", test))]
+mod check;
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IssueKind {
+  Dangling,
+  OneWay,
+}
+
+impl fmt::Display for IssueKind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        IssueKind::Dangling => "Dangling reference",
+        IssueKind::OneWay => "One-way link",
+      }
+    )
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct IntegrityIssue {
+  pub kind: IssueKind,
+  pub collection: String,
+  pub id: String,
+  pub title: String,
+  pub field: String,
+  pub target_collection: String,
+  pub target_id: String,
+}
+
+impl fmt::Display for IntegrityIssue {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self.kind {
+      IssueKind::Dangling => write!(
+        f,
+        "{} {} ({}) {} references missing {} {}",
+        self.collection, self.title, self.id, self.field, self.target_collection, self.target_id
+      ),
+      IssueKind::OneWay => write!(
+        f,
+        "{} {} ({}) {} lists {} {} which does not link back",
+        self.collection, self.title, self.id, self.field, self.target_collection, self.target_id
+      ),
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct IntegrityReport {
+  pub documents: usize,
+  pub issues: Vec<IntegrityIssue>,
+}
+
+pub mod prelude {
+  #[cfg(feature = "server")]
+  pub use super::check::check_integrity;
+  pub use super::IntegrityReport;
+}
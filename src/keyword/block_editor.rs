@@ -0,0 +1,185 @@
+use bson::oid::ObjectId;
+use dioxus::prelude::*;
+
+use super::internal::*;
+use super::term::Term;
+use crate::character::prelude::{Capability, Defense};
+use crate::common::*;
+use crate::rules::prelude::*;
+use crate::server::prelude::KeywordCache;
+
+fn new_roll() -> Roll {
+  return Roll {
+    class: RollClass::Check,
+    opening: None,
+    keyword: None,
+    capability: None,
+    defense: None,
+    alternate_defense: None,
+    modifier: None,
+    custom_modifier: None,
+    each: None,
+    custom_target: None,
+    difficulty: None,
+    target: None,
+  };
+}
+
+fn block_from(snippets: Vec<Snippet>) -> Option<RulesBlock> {
+  return match snippets.is_empty() {
+    true => None,
+    false => Some(snippets),
+  };
+}
+
+fn edit_snippet<T: 'static>(
+  snippets: &[Snippet], index: usize, onchange: EventHandler<Option<RulesBlock>>,
+  apply: fn(&mut Snippet, T),
+) -> impl FnMut(T) + 'static {
+  let snippets = snippets.to_vec();
+  move |value: T| {
+    let mut updated = snippets.clone();
+    apply(&mut updated[index], value);
+    onchange.call(block_from(updated));
+  }
+}
+
+#[component]
+pub(crate) fn RulesBlockEditor(
+  block: Option<RulesBlock>, onchange: EventHandler<Option<RulesBlock>>,
+) -> Element {
+  let KeywordCache(ref keyword_cache) = use_context();
+  let mut keywords: Vec<Keyword> = keyword_cache.into_vec();
+  keywords.sort();
+  let keyword_ids: Vec<ObjectId> = keywords.iter().map(|keyword| keyword.id).collect();
+  let keyword_titles: Vec<String> = keywords.into_iter().map(|keyword| keyword.title).collect();
+  let snippets = block.unwrap_or_default();
+  let added = move |snippet: Snippet, snippets: &[Snippet]| {
+    let snippets = snippets.to_vec();
+    move |_| {
+      let mut updated = snippets.clone();
+      updated.push(snippet.clone());
+      onchange.call(block_from(updated));
+    }
+  };
+  rsx! {
+    for (index, snippet) in snippets.clone().into_iter().enumerate() {
+      FormRow {
+        title: format!("Snippet {}", index + 1),
+        match (snippet.roll, snippet.term) {
+          (Some(roll), _) => rsx! {
+            EnumSelect {
+              options: RollClass::ordered(),
+              selected: Some(roll.class),
+              onchange: edit_snippet(&snippets, index, onchange, |snippet, value| {
+                if let (Some(roll), Some(class)) = (snippet.roll.as_mut(), value) {
+                  roll.class = class;
+                }
+              }),
+            }
+            EnumSelect {
+              options: Capability::ordered(),
+              selected: roll.capability,
+              optional: true,
+              onchange: edit_snippet(&snippets, index, onchange, |snippet, value| {
+                if let Some(roll) = snippet.roll.as_mut() {
+                  roll.capability = value;
+                }
+              }),
+            }
+            EnumSelect {
+              options: Defense::ordered(),
+              selected: roll.defense,
+              optional: true,
+              onchange: edit_snippet(&snippets, index, onchange, |snippet, value| {
+                if let Some(roll) = snippet.roll.as_mut() {
+                  roll.defense = value;
+                }
+              }),
+            }
+            EnumSelect {
+              options: Modifier::ordered(),
+              selected: roll.modifier,
+              optional: true,
+              onchange: edit_snippet(&snippets, index, onchange, |snippet, value| {
+                if let Some(roll) = snippet.roll.as_mut() {
+                  roll.modifier = value;
+                }
+              }),
+            }
+          },
+          (None, Some(term)) => rsx! {
+            EnumSelect {
+              options: keyword_ids.clone(),
+              labels: keyword_titles.clone(),
+              selected: term.keyword_id,
+              onchange: edit_snippet(&snippets, index, onchange, |snippet, value| {
+                if let Some(term) = snippet.term.as_mut() {
+                  term.keyword_id = value;
+                }
+              }),
+            }
+            EnumSelect {
+              options: Tense::ordered(),
+              selected: term.tense,
+              optional: true,
+              onchange: edit_snippet(&snippets, index, onchange, |snippet, value| {
+                if let Some(term) = snippet.term.as_mut() {
+                  term.tense = value;
+                }
+              }),
+            }
+            "Italics"
+            OptionalFlagField {
+              value: term.italics,
+              onchange: edit_snippet(&snippets, index, onchange, |snippet, value| {
+                if let Some(term) = snippet.term.as_mut() {
+                  term.italics = value;
+                }
+              }),
+            }
+          },
+          (None, None) => rsx! {
+            OptionalTextField {
+              value: snippet.text,
+              multiline: true,
+              onchange: edit_snippet(&snippets, index, onchange, |snippet, value| {
+                snippet.text = value;
+              }),
+            }
+          },
+        }
+        div {
+          class: "chip",
+          onclick: {
+            let snippets = snippets.clone();
+            move |_| {
+              let mut updated = snippets.clone();
+              updated.remove(index);
+              onchange.call(block_from(updated));
+            }
+          },
+          "Remove ✕"
+        }
+      }
+    }
+    FormRow {
+      title: "Add snippet",
+      div {
+        class: "chip",
+        onclick: added(Snippet::from_blurb(String::new()), &snippets),
+        "Text"
+      }
+      div {
+        class: "chip",
+        onclick: added(Snippet { term: Some(Term::default()), ..Snippet::default() }, &snippets),
+        "Term"
+      }
+      div {
+        class: "chip",
+        onclick: added(Snippet { roll: Some(new_roll()), ..Snippet::default() }, &snippets),
+        "Roll"
+      }
+    }
+  }
+}
@@ -30,6 +30,18 @@ impl fmt::Display for KeywordClass {
   }
 }
 
+impl KeywordClass {
+  pub fn ordered() -> Vec<KeywordClass> {
+    return vec![
+      KeywordClass::CoreRule,
+      KeywordClass::Attribute,
+      KeywordClass::Term,
+      KeywordClass::Condition,
+      KeywordClass::Classifier,
+    ];
+  }
+}
+
 pub trait KeywordClassified {
   fn get_keyword_ids(&self) -> HashSet<ObjectId>;
 }
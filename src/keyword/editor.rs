@@ -0,0 +1,105 @@
+use dioxus::prelude::*;
+
+use super::block_editor::RulesBlockEditor;
+use super::internal::*;
+use super::term::KeywordCard;
+use crate::common::*;
+use crate::server::prelude::*;
+use crate::Route;
+
+#[component]
+pub fn KeywordEditor(keyword: Keyword, #[props(default)] is_new: bool) -> Element {
+  let KeywordCache(keyword_cache) = use_context::<KeywordCache>();
+  let mut draft: Signal<Keyword> = use_signal(|| keyword.clone());
+  let mut status: Signal<SaveStatus> = use_signal(|| SaveStatus::Idle);
+  let nav = navigator();
+  let current = draft();
+  let valid = !current.title.trim().is_empty();
+  let saving = status() == SaveStatus::Saving;
+  let save_class = if saving || !valid { "disabled" } else { "" };
+  let tenses = current.tenses.clone().unwrap_or_default();
+  rsx! {
+    div {
+      class: "row gap-large align-start",
+      div {
+        class: "card grid dim-keywords no-print",
+        FormRow {
+          title: "Title",
+          TextField { value: current.title.clone(), onchange: move |value| draft.write().title = value }
+        }
+        FormRow {
+          title: "Class",
+          EnumSelect {
+            options: KeywordClass::ordered(),
+            selected: Some(current.class.clone()),
+            onchange: move |value: Option<KeywordClass>| if let Some(class) = value { draft.write().class = class },
+          }
+        }
+        FormRow {
+          title: "Blurb",
+          OptionalTextField {
+            value: current.blurb.clone(),
+            multiline: true,
+            onchange: move |value| draft.write().blurb = value,
+          }
+        }
+        div { class: "uv-full subheading", "Rules" }
+        RulesBlockEditor {
+          block: current.rules.clone(),
+          onchange: move |rules| draft.write().rules = rules,
+        }
+        div { class: "uv-full subheading", "Tenses" }
+        for tense in Tense::ordered() {
+          FormRow {
+            title: tense.to_string(),
+            OptionalTextField {
+              value: tenses.get(&tense),
+              onchange: move |value| {
+                let mut keyword = draft.write();
+                let mut tenses = keyword.tenses.clone().unwrap_or_default();
+                tenses.set(&tense, value);
+                keyword.tenses = if tenses.is_empty() { None } else { Some(tenses) };
+              },
+            }
+          }
+        }
+        div {
+          class: "uv-full row gap-large spacer",
+          div {
+            class: "next-button {save_class}",
+            onclick: move |_| {
+              if saving || !valid {
+                return;
+              }
+              let keyword = draft();
+              let cache = keyword_cache.clone();
+              status.set(SaveStatus::Saving);
+              spawn(async move {
+                let result = match is_new {
+                  true => insert_keyword(keyword).await,
+                  false => update_keyword(keyword).await,
+                };
+                match result {
+                  Ok(_) => {
+                    status.set(SaveStatus::Idle);
+                    cache.restart();
+                    nav.push(Route::KeywordsPage {});
+                  },
+                  Err(error) => status.set(SaveStatus::Errored(error.to_string())),
+                }
+              });
+            },
+            if saving { "Saving ..." } else { "Save keyword" }
+          }
+        }
+        if let SaveStatus::Errored(error) = status() {
+          div { class: "uv-full errored", "Unable to save keyword: {error}" }
+        }
+      }
+      div {
+        class: "column",
+        KeywordCard { keyword: current }
+      }
+    }
+  }
+}
@@ -1,5 +1,8 @@
 mod aspect;
+mod block_editor;
 mod classifiers;
+pub mod editor;
+mod picker;
 mod tense;
 mod term;
 
@@ -58,6 +61,8 @@ impl Ord for Keyword {
 
 pub mod prelude {
   pub(crate) use super::classifiers::{terms_and_conditions, partitioned_terms_and_conditions, KeywordClassified};
+  pub(crate) use super::picker::KeywordPicker;
+  pub use super::classifiers::KeywordClass;
   pub use super::term::Term;
   pub(crate) use super::term::{KeywordBlocks, KeywordCard, KeywordCards, TermSnippet, TermsConditions};
   pub use super::Keyword;
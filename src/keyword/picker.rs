@@ -0,0 +1,26 @@
+use bson::oid::ObjectId;
+use dioxus::prelude::*;
+
+use super::internal::*;
+use crate::common::{IdPicker, PickerItem};
+use crate::server::prelude::KeywordCache;
+
+#[component]
+pub(crate) fn KeywordPicker(
+  selected: Vec<ObjectId>, onchange: EventHandler<Vec<ObjectId>>,
+) -> Element {
+  let KeywordCache(ref keyword_cache) = use_context();
+  let mut keywords: Vec<Keyword> = keyword_cache.into_vec();
+  keywords.sort();
+  let items: Vec<PickerItem> = keywords
+    .into_iter()
+    .map(|keyword| PickerItem {
+      id: keyword.id,
+      label: format!("{} ({})", keyword.title, keyword.class),
+      title: keyword.title,
+    })
+    .collect();
+  rsx! {
+    IdPicker { items, selected, placeholder: "Add keyword", onchange }
+  }
+}
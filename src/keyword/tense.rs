@@ -1,5 +1,6 @@
 use super::internal::*;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 impl Keyword {
   pub fn title_as(&self, tense: &Option<Tense>) -> String {
@@ -24,7 +25,37 @@ pub enum Tense {
   Adjective,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+impl fmt::Display for Tense {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        Tense::Past => "Past",
+        Tense::Present => "Present",
+        Tense::Future => "Future",
+        Tense::Singular => "Singular",
+        Tense::Plural => "Plural",
+        Tense::Adjective => "Adjective",
+      }
+    )
+  }
+}
+
+impl Tense {
+  pub fn ordered() -> Vec<Tense> {
+    return vec![
+      Tense::Past,
+      Tense::Present,
+      Tense::Future,
+      Tense::Singular,
+      Tense::Plural,
+      Tense::Adjective,
+    ];
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Tenses {
   pub past: Option<String>,
@@ -46,4 +77,19 @@ impl Tenses {
       Tense::Adjective => self.adjective.clone(),
     }
   }
+
+  pub fn set(&mut self, tense: &Tense, value: Option<String>) {
+    match tense {
+      Tense::Past => self.past = value,
+      Tense::Present => self.present = value,
+      Tense::Future => self.future = value,
+      Tense::Singular => self.singular = value,
+      Tense::Plural => self.plural = value,
+      Tense::Adjective => self.adjective = value,
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    return Tense::ordered().iter().all(|tense| self.get(tense).is_none());
+  }
 }
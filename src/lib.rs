@@ -0,0 +1,107 @@
+mod asset;
+mod character;
+mod common;
+mod equipment;
+mod filter;
+mod integrity;
+mod keyword;
+mod modifiers;
+mod pages;
+mod panels;
+mod path;
+mod play;
+mod progression;
+mod rules;
+mod server;
+mod skill;
+
+use dioxus::prelude::*;
+use pages::*;
+use panels::*;
+
+use crate::server::prelude::{ArmorCache, KeywordCache, PathCache, SkillCache, WeaponCache};
+
+const FAVICON: Asset = asset!("assets/favicon.ico");
+const MAIN_CSS: Asset = asset!("assets/main.css");
+
+#[cfg(feature = "server")]
+pub use server::cli::run_integrity;
+
+pub fn launch() {
+  #[cfg(feature = "server")]
+  if let Some(code) = std::env::args().nth(1).and_then(|command| server::cli::run_command(&command)) {
+    std::process::exit(code);
+  }
+  dioxus::launch(App);
+}
+
+#[derive(Debug, Clone, Routable, PartialEq)]
+#[rustfmt::skip]
+pub enum Route {
+  #[layout(Navbar)]
+
+  #[route("/")] MainRules {},
+  #[route("/builder")] CharacterBuilder {},
+  #[route("/filter")] SkillFilterPage {},
+  #[nest("/keywords")]
+    #[route("/")] KeywordsPage {},
+    #[route("/new")] NewKeywordPage {},
+    #[route("/:id/edit")] EditKeywordPage { id: String },
+  #[end_nest]
+  #[route("/blank-sheet")] BlankSheetPage {},
+  
+  #[nest("/paths")]
+    #[route("/")] PathsPage {},
+    #[route("/new")] NewPathPage {},
+    #[route("/:id")] SinglePath { id: String },
+    #[route("/:id/edit")] EditPathPage { id: String },
+  #[end_nest]
+
+  #[nest("/equipment")]
+    #[route("/")] EquipmentPage {},
+    #[route("/weapons/new")] NewWeaponPage {},
+    #[route("/weapons/:id/edit")] EditWeaponPage { id: String },
+    #[route("/armor/new")] NewArmorPage {},
+    #[route("/armor/:id/edit")] EditArmorPage { id: String },
+  #[end_nest]
+
+  #[nest("/skills")]
+    #[route("/")] SkillsPage {},
+    #[route("/new")] NewSkillPage {},
+    #[route("/:id")] SingleSkillPage { id: String },
+    #[route("/:id/edit")] EditSkillPage { id: String },
+  #[end_nest]
+  
+  #[nest("/admin")]
+    #[layout(AdminPage)]
+      #[route("/content")] ContentPackPage {},
+      #[route("/integrity")] IntegrityPage {},
+      #[route("/diagnostics")] DiagnosticsPage {},
+      #[route("/lint")] ContentLintPage {},
+    #[end_layout]
+  #[end_nest]
+
+  #[nest("/sheets")]
+    #[route("/")] CharacterSheetsPage {},
+    #[route("/:id")] SingleCharacterSheetPage { id: String },
+    #[route("/:id/level-up")] LevelUpPage { id: String },
+    #[route("/:id/play/:session")] PlayPage { id: String, session: String },
+  #[end_nest]
+
+  #[nest("/campaigns")]
+    #[route("/:campaign/rolls")] RollLogPage { campaign: String },
+}
+
+#[component]
+fn App() -> Element {
+  KeywordCache::use_context_provider();
+  SkillCache::use_context_provider();
+  PathCache::use_context_provider();
+  WeaponCache::use_context_provider();
+  ArmorCache::use_context_provider();
+  rsx! {
+    document::Link { rel: "icon", href: FAVICON }
+    document::Link { rel: "stylesheet", href: MAIN_CSS }
+    Router::<Route> {}
+  }
+}
@@ -0,0 +1,338 @@
+use dioxus::prelude::*;
+
+use crate::common::*;
+use crate::integrity::prelude::*;
+use crate::server::prelude::*;
+use crate::skill::prelude::*;
+use crate::Route;
+
+fn json_data_url(json: &str) -> String {
+  let mut url = String::from("data:application/json;charset=utf-8,");
+  for byte in json.bytes() {
+    match byte {
+      b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => url.push(byte as char),
+      _ => url.push_str(&format!("%{byte:02X}")),
+    }
+  }
+  return url;
+}
+
+#[component]
+pub fn AdminPage() -> Element {
+  return rsx! {
+    div {
+      class: "row gap-xlarge underhang no-print",
+      Link { to: Route::ContentPackPage {}, "Content Packs" }
+      Link { to: Route::IntegrityPage {}, "Integrity" }
+      Link { to: Route::DiagnosticsPage {}, "Diagnostics" }
+      Link { to: Route::ContentLintPage {}, "Content Lint" }
+    }
+    Outlet::<Route> {}
+  };
+}
+
+#[component]
+pub fn ContentPackPage() -> Element {
+  return rsx! {
+    div {
+      class: "column gap-large",
+      ContentPackExport {}
+      ContentPackImport {}
+    }
+  };
+}
+
+#[component]
+fn ContentPackExport() -> Element {
+  let mut include_creatures = use_signal(|| false);
+  let mut exported: Signal<Option<Result<String, String>>> = use_signal(|| None);
+  let mut exporting = use_signal(|| false);
+  let button_class = if exporting() { "disabled" } else { "" };
+  rsx! {
+    div {
+      class: "card grid dim-keywords",
+      div { class: "uv-full subheading", "Export" }
+      FormRow {
+        title: "Creatures",
+        CheckboxField { checked: include_creatures(), onchange: move |value| include_creatures.set(value) }
+        "Include character sheets"
+      }
+      div {
+        class: "uv-full row gap-large",
+        div {
+          class: "next-button {button_class}",
+          onclick: move |_| {
+            if exporting() {
+              return;
+            }
+            exporting.set(true);
+            spawn(async move {
+              let result = export_content_pack(include_creatures()).await;
+              exported.set(Some(result.map_err(|error| error.to_string())));
+              exporting.set(false);
+            });
+          },
+          if exporting() { "Exporting ..." } else { "Export content pack" }
+        }
+        if let Some(Ok(json)) = exported() {
+          a {
+            href: json_data_url(&json),
+            download: "relic-content-pack-v{CONTENT_PACK_VERSION}.json",
+            "Download"
+          }
+        }
+      }
+      match exported() {
+        Some(Ok(json)) => rsx! {
+          textarea { class: "uv-full input-text", readonly: true, rows: 12, value: json }
+        },
+        Some(Err(error)) => rsx! {
+          div { class: "uv-full errored", "Unable to export content pack: {error}" }
+        },
+        None => rsx! {},
+      }
+    }
+  }
+}
+
+#[component]
+fn ContentPackImport() -> Element {
+  let KeywordCache(keyword_cache) = use_context::<KeywordCache>();
+  let SkillCache(skill_cache) = use_context::<SkillCache>();
+  let PathCache(path_cache) = use_context::<PathCache>();
+  let WeaponCache(weapon_cache) = use_context::<WeaponCache>();
+  let ArmorCache(armor_cache) = use_context::<ArmorCache>();
+  let mut pack = use_signal(String::new);
+  let mut options = use_signal(ImportOptions::default);
+  let mut importing = use_signal(|| false);
+  let mut outcome: Signal<Option<Result<ImportReport, String>>> = use_signal(|| None);
+  let current = options();
+  let disabled = importing() || pack().trim().is_empty();
+  let button_class = if disabled { "disabled" } else { "" };
+  rsx! {
+    div {
+      class: "card grid dim-keywords",
+      div { class: "uv-full subheading", "Import" }
+      FormRow {
+        title: "Content Pack",
+        TextField { value: pack(), multiline: true, onchange: move |value| pack.set(value) }
+      }
+      FormRow {
+        title: "Ids",
+        EnumSelect {
+          options: IdMode::ordered(),
+          selected: Some(current.id_mode.clone()),
+          onchange: move |value: Option<IdMode>| if let Some(id_mode) = value { options.write().id_mode = id_mode },
+        }
+      }
+      FormRow {
+        title: "Conflicts",
+        EnumSelect {
+          options: ConflictMode::ordered(),
+          selected: Some(current.conflict_mode.clone()),
+          onchange: move |value: Option<ConflictMode>| {
+            if let Some(conflict_mode) = value { options.write().conflict_mode = conflict_mode }
+          },
+        }
+      }
+      FormRow {
+        title: "Options",
+        CheckboxField {
+          checked: current.include_creatures,
+          onchange: move |value| options.write().include_creatures = value,
+        }
+        "Include character sheets"
+        CheckboxField { checked: current.dry_run, onchange: move |value| options.write().dry_run = value }
+        "Preview only"
+      }
+      div {
+        class: "uv-full row",
+        div {
+          class: "next-button {button_class}",
+          onclick: move |_| {
+            if disabled {
+              return;
+            }
+            let keyword_cache = keyword_cache.clone();
+            let skill_cache = skill_cache.clone();
+            let path_cache = path_cache.clone();
+            let weapon_cache = weapon_cache.clone();
+            let armor_cache = armor_cache.clone();
+            importing.set(true);
+            spawn(async move {
+              let result = import_content_pack(pack(), options()).await;
+              if let Ok(report) = &result {
+                if !report.dry_run {
+                  keyword_cache.restart();
+                  skill_cache.restart();
+                  path_cache.restart();
+                  weapon_cache.restart();
+                  armor_cache.restart();
+                }
+              }
+              outcome.set(Some(result.map_err(|error| error.to_string())));
+              importing.set(false);
+            });
+          },
+          if importing() { "Importing ..." } else { "Import content pack" }
+        }
+      }
+      match outcome() {
+        Some(Ok(report)) => rsx! { ImportReportDetails { report } },
+        Some(Err(error)) => rsx! {
+          div { class: "uv-full errored", "Unable to import content pack: {error}" }
+        },
+        None => rsx! {},
+      }
+    }
+  }
+}
+
+#[component]
+fn ImportReportDetails(report: ImportReport) -> Element {
+  let heading = match report.dry_run {
+    true => format!("Preview of version {} content pack", report.version),
+    false => format!("Imported version {} content pack", report.version),
+  };
+  rsx! {
+    div { class: "uv-full subheading", "{heading}" }
+    for collection in report.collections {
+      FormRow {
+        title: collection.collection,
+        "{collection.inserted} new, {collection.replaced} replaced, {collection.skipped} skipped"
+      }
+    }
+    if !report.conflicts.is_empty() {
+      div { class: "uv-full subheading", "Conflicts" }
+      for conflict in report.conflicts {
+        FormRow {
+          title: conflict.collection,
+          "{conflict.title} ({conflict.id}): {conflict.resolution}"
+        }
+      }
+    }
+  }
+}
+
+#[component]
+pub fn IntegrityPage() -> Element {
+  let mut report = use_resource(check_compendium_integrity);
+  let result = report.read().clone();
+  rsx! {
+    div {
+      class: "card grid dim-keywords",
+      div {
+        class: "uv-full row align-center",
+        div { class: "subheading", "Referential Integrity" }
+        div { class: "next-button", onclick: move |_| report.restart(), "Check again" }
+      }
+      match result {
+        None => rsx! { div { class: "uv-full", "Checking references ..." } },
+        Some(Err(error)) => rsx! {
+          div { class: "uv-full errored", "Unable to check integrity: {error}" }
+        },
+        Some(Ok(report)) => rsx! { IntegrityReportDetails { report } },
+      }
+    }
+  }
+}
+
+#[component]
+fn IntegrityReportDetails(report: IntegrityReport) -> Element {
+  let summary = format!("Checked {} documents, found {} issues", report.documents, report.issues.len());
+  rsx! {
+    div { class: "uv-full", "{summary}" }
+    for issue in report.issues {
+      FormRow {
+        title: issue.kind.to_string(),
+        "{issue}"
+      }
+    }
+  }
+}
+
+#[component]
+pub fn DiagnosticsPage() -> Element {
+  let mut load_errors = use_resource(get_load_errors);
+  let result = load_errors.read().clone();
+  rsx! {
+    div {
+      class: "card grid dim-keywords",
+      div {
+        class: "uv-full row align-center",
+        div { class: "subheading", "Load Diagnostics" }
+        div { class: "next-button", onclick: move |_| load_errors.restart(), "Reload" }
+      }
+      match result {
+        None => rsx! { div { class: "uv-full", "Loading documents ..." } },
+        Some(Err(error)) => rsx! {
+          div { class: "uv-full errored", "Unable to load documents: {error}" }
+        },
+        Some(Ok(errors)) if errors.is_empty() => rsx! {
+          div { class: "uv-full", "Every document loaded cleanly" }
+        },
+        Some(Ok(errors)) => rsx! {
+          div { class: "uv-full", "{errors.len()} documents were skipped while loading" }
+          for error in errors {
+            LoadErrorRow { error }
+          }
+        },
+      }
+    }
+  }
+}
+
+#[component]
+fn LoadErrorRow(error: LoadError) -> Element {
+  let id = error.id.unwrap_or("missing _id".into());
+  rsx! {
+    FormRow {
+      title: error.collection,
+      div {
+        class: "column",
+        div { class: "highlight", "{id}" }
+        div { "at {error.path}" }
+        div { class: "errored", "{error.message}" }
+      }
+    }
+  }
+}
+
+#[component]
+pub fn ContentLintPage() -> Element {
+  let SkillCache(ref skill_cache) = use_context();
+  if let Some(element) = skill_cache.status_element() {
+    return element;
+  }
+  let lints = lint_skills(&skill_cache.into_vec());
+  rsx! {
+    div {
+      class: "card grid dim-keywords",
+      div { class: "uv-full subheading", "Content Lint" }
+      if lints.is_empty() {
+        div { class: "uv-full", "Every skill renders without fallback text" }
+      } else {
+        div { class: "uv-full", "{lints.len()} rules render with fallback text" }
+      }
+      for lint in lints {
+        ContentLintRow { lint }
+      }
+    }
+  }
+}
+
+#[component]
+fn ContentLintRow(lint: ContentLint) -> Element {
+  let id = lint.skill_id.clone();
+  rsx! {
+    div {
+      class: "uv-title highlight",
+      Link { to: Route::EditSkillPage { id }, "{lint.skill_title}" }
+    }
+    div {
+      class: "uv-details column",
+      div { "{lint.location} {lint.field}: \"{lint.rendered}\"" }
+      div { class: "errored", "{lint.reason}" }
+    }
+  }
+}
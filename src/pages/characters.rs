@@ -3,8 +3,16 @@ use std::collections::HashSet;
 use bson::oid::ObjectId;
 use dioxus::prelude::*;
 
-use crate::character::prelude::{BodyStats, CharacterSheet, FillableSheet, SheetDetails, TrainingRanks};
-use crate::server::prelude::CharacterSheetCache;
+use crate::character::prelude::{
+  BodyStats, CharacterSheet, FillableSheet, SheetControls, SheetDetails, SheetEnchantments,
+  SheetInventory, SheetLegality, TrainingRanks,
+};
+use crate::play::prelude::{PlayTracker, RollLog};
+use crate::progression::component::LevelUpWizard;
+use crate::server::prelude::{
+  list_play_sessions, status_element_paths_skills_keywords, CharacterSheetCache,
+};
+use crate::Route;
 use crate::rules::prelude::AttributeRanks;
 
 
@@ -30,11 +38,99 @@ pub fn SingleCharacterSheetPage(id: String) -> Element {
       div { "Character sheet not found" }
     }
   };
+  // Keyed on the revision so edits to the sheet re-run the check
+  let revision = sheet.revision.unwrap_or_default();
   return rsx! {
+    SheetControls { sheet: sheet.clone() }
+    div {
+      class: "column no-print underhang",
+      SheetLegality { key: "{id}-{revision}", id }
+    }
+    SheetInventory { sheet: sheet.clone() }
+    SheetEnchantments { sheet: sheet.clone() }
     SheetDetails { sheet }
   }
 }
 
+#[component]
+pub fn LevelUpPage(id: String) -> Element {
+  CharacterSheetCache::use_context_provider();
+  let CharacterSheetCache(ref sheet_cache) = use_context();
+  if let Some(elements) = status_element_paths_skills_keywords() {
+    return elements;
+  }
+  let Some( sheet ) = sheet_cache.from_id(&id) else {
+    return rsx! {
+      div { "Character sheet not found" }
+    }
+  };
+  return rsx! {
+    LevelUpWizard { sheet }
+  }
+}
+
+#[component]
+pub fn PlayPage(id: String, session: String) -> Element {
+  CharacterSheetCache::use_context_provider();
+  let CharacterSheetCache(ref sheet_cache) = use_context();
+  let sessions = use_resource(use_reactive!(|id| list_play_sessions(id)));
+  let mut new_session: Signal<String> = use_signal(String::new);
+  let nav = navigator();
+  if let Some(elements) = status_element_paths_skills_keywords() {
+    return elements;
+  }
+  let Some( sheet ) = sheet_cache.from_id(&id) else {
+    return rsx! {
+      div { "Character sheet not found" }
+    }
+  };
+  let session_names = match &*sessions.read() {
+    Some(Ok(names)) => names.clone(),
+    _ => Vec::new(),
+  };
+  let open_id = id.clone();
+  return rsx! {
+    div {
+      class: "row align-center no-print underhang",
+      for name in session_names {
+        Link {
+          class: if name == session { "chip highlight" } else { "chip" },
+          to: Route::PlayPage { id: id.clone(), session: name.clone() },
+          "{name}"
+        }
+      }
+      input {
+        class: "input-text", r#type: "text",
+        placeholder: "New session",
+        value: new_session(),
+        oninput: move |event| new_session.set(event.value()),
+      }
+      div {
+        class: "next-button",
+        onclick: move |_| {
+          let session = new_session().trim().to_string();
+          if !session.is_empty() {
+            nav.push(Route::PlayPage { id: open_id.clone(), session });
+          }
+        },
+        "Open"
+      }
+    }
+    PlayTracker { sheet, session }
+  }
+}
+
+#[component]
+pub fn RollLogPage(campaign: String) -> Element {
+  return rsx! {
+    div {
+      class: "row align-center underhang",
+      div { class: "subheading", "{campaign}" }
+    }
+    RollLog { campaign }
+  }
+}
+
 
 #[component]
 pub fn BlankSheetPage() -> Element {
@@ -53,6 +149,7 @@ pub fn BlankSheetPage() -> Element {
       tenacity: Some(0),
       ..AttributeRanks::default()
     }, 
+    attribute_layers: None,
     training: TrainingRanks::default(), 
     body: BodyStats { hp: 30, constitution: 4, speed: 6 }, 
     paths: HashSet::new(), 
@@ -60,8 +157,10 @@ pub fn BlankSheetPage() -> Element {
     flows: None, 
     armor: None, 
     weapons: None, 
+    inventory: None, 
     resistances: None, 
     expertise: None,
+    revision: None,
   });
   return rsx! {
     FillableSheet { character_sheet }
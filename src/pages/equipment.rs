@@ -0,0 +1,120 @@
+use dioxus::prelude::*;
+
+use crate::common::{StaggeredCell, StaggeredGrid};
+use crate::equipment::armor::{Armor, ArmorEntry};
+use crate::equipment::editor::{ArmorEditor, WeaponEditor};
+use crate::equipment::weapon::{Weapon, WeaponEntry};
+use crate::keyword::prelude::*;
+use crate::server::prelude::*;
+use crate::Route;
+
+#[component]
+pub fn EquipmentPage() -> Element {
+  let WeaponCache(ref weapon_cache) = use_context();
+  let ArmorCache(ref armor_cache) = use_context();
+  let KeywordCache(ref keyword_cache) = use_context();
+  let (Some(mut weapons), Some(mut armor)) =
+    (weapon_cache.into_result_vec(), armor_cache.into_result_vec())
+  else {
+    return rsx! {
+      div { "Loading Equipment ..." }
+    };
+  };
+  weapons.sort_by(|a, b| a.title.cmp(&b.title));
+  armor.sort_by(|a, b| a.title.cmp(&b.title));
+  let mut property_ids = Vec::new();
+  for weapon in &weapons {
+    for id in weapon.keyword_ids.iter().flatten() {
+      if !property_ids.contains(id) {
+        property_ids.push(*id);
+      }
+    }
+  }
+  let mut properties = keyword_cache.from_object_ids(&property_ids);
+  properties.sort();
+  return rsx! {
+    div {
+      class: "row align-center gap-large no-print underhang",
+      Link { to: Route::NewWeaponPage {}, "New weapon" }
+      Link { to: Route::NewArmorPage {}, "New armor" }
+    }
+    div { class: "subheading", "Weapons" }
+    StaggeredGrid {
+      for weapon in weapons {
+        StaggeredCell {
+          Link {
+            class: "no-print",
+            to: Route::EditWeaponPage { id: weapon.id.to_hex() },
+            "Edit"
+          }
+          WeaponEntry { weapon }
+        }
+      }
+    }
+    div { class: "subheading", "Armor" }
+    StaggeredGrid {
+      for armor in armor {
+        StaggeredCell {
+          Link {
+            class: "no-print",
+            to: Route::EditArmorPage { id: armor.id.to_hex() },
+            "Edit"
+          }
+          ArmorEntry { armor }
+        }
+      }
+    }
+    if !properties.is_empty() {
+      div { class: "subheading", "Weapon properties" }
+      StaggeredGrid {
+        for keyword in properties {
+          StaggeredCell {
+            KeywordCard { keyword }
+          }
+        }
+      }
+    }
+  };
+}
+
+#[component]
+pub fn NewWeaponPage() -> Element {
+  let weapon = Weapon { title: String::new(), ..Default::default() };
+  return rsx! {
+    WeaponEditor { weapon, is_new: true }
+  };
+}
+
+#[component]
+pub fn EditWeaponPage(id: String) -> Element {
+  let WeaponCache(ref weapon_cache) = use_context();
+  let Some(weapon) = weapon_cache.from_id(&id) else {
+    return rsx! {
+      div { "Cannot find weapon with id: {id}" }
+    };
+  };
+  return rsx! {
+    WeaponEditor { key: "{id}", weapon }
+  };
+}
+
+#[component]
+pub fn NewArmorPage() -> Element {
+  let armor = Armor { title: String::new(), ..Default::default() };
+  return rsx! {
+    ArmorEditor { armor, is_new: true }
+  };
+}
+
+#[component]
+pub fn EditArmorPage(id: String) -> Element {
+  let ArmorCache(ref armor_cache) = use_context();
+  let Some(armor) = armor_cache.from_id(&id) else {
+    return rsx! {
+      div { "Cannot find armor with id: {id}" }
+    };
+  };
+  return rsx! {
+    ArmorEditor { key: "{id}", armor }
+  };
+}
@@ -1,10 +1,63 @@
 use dioxus::prelude::*;
 
+use crate::keyword::editor::KeywordEditor;
 use crate::keyword::prelude::*;
+use crate::server::prelude::*;
+use crate::Route;
 
 #[component]
 pub fn KeywordsPage() -> Element {
+  let KeywordCache(ref keyword_cache) = use_context();
+  let mut keywords = keyword_cache.into_vec();
+  keywords.sort();
+  let nav = navigator();
   return rsx! {
+    div {
+      class: "row align-center gap-large no-print underhang",
+      Link { to: Route::NewKeywordPage {}, "New keyword" }
+      select {
+        onchange: move |event| {
+          let id = event.value();
+          if !id.is_empty() {
+            nav.push(Route::EditKeywordPage { id });
+          }
+        },
+        option { value: "", selected: true, "Edit keyword" }
+        for keyword in keywords {
+          option { value: keyword.id.to_hex(), "{keyword.title} ({keyword.class_title()})" }
+        }
+      }
+    }
     TermsConditions {}
   }
 }
+
+#[component]
+pub fn NewKeywordPage() -> Element {
+  if let Some(elements) = status_element_paths_skills_keywords() {
+    return elements;
+  }
+  let keyword = Keyword {
+    title: String::new(),
+    ..Default::default()
+  };
+  return rsx! {
+    KeywordEditor { keyword, is_new: true }
+  };
+}
+
+#[component]
+pub fn EditKeywordPage(id: String) -> Element {
+  if let Some(elements) = status_element_paths_skills_keywords() {
+    return elements;
+  }
+  let KeywordCache(ref keyword_cache) = use_context();
+  let Some(keyword) = keyword_cache.from_id(&id) else {
+    return rsx! {
+      div { "Cannot find keyword with id: {id}" }
+    };
+  };
+  return rsx! {
+    KeywordEditor { key: "{id}", keyword }
+  };
+}
@@ -1,9 +1,17 @@
+mod admin;
 mod characters;
+mod equipment;
 mod keywords;
 mod paths;
 mod skill_filter;
 
-pub use characters::{CharacterSheetsPage, SingleCharacterSheetPage, BlankSheetPage};
-pub use keywords::{KeywordsPage};
-pub use paths::{PathsPage, SinglePath};
+pub use admin::{AdminPage, ContentLintPage, ContentPackPage, DiagnosticsPage, IntegrityPage};
+pub use characters::{
+  BlankSheetPage, CharacterSheetsPage, LevelUpPage, PlayPage, RollLogPage, SingleCharacterSheetPage,
+};
+pub use equipment::{
+  EditArmorPage, EditWeaponPage, EquipmentPage, NewArmorPage, NewWeaponPage,
+};
+pub use keywords::{EditKeywordPage, KeywordsPage, NewKeywordPage};
+pub use paths::{EditPathPage, NewPathPage, PathsPage, SinglePath};
 pub use skill_filter::SkillFilterPage;
@@ -1,5 +1,9 @@
 use crate::path::components::*;
+use crate::path::editor::PathEditor;
+use crate::path::prelude::*;
+use crate::rules::prelude::Tier;
 use crate::server::prelude::*;
+use crate::Route;
 use dioxus::prelude::*;
 
 #[component]
@@ -10,6 +14,7 @@ pub fn PathsPage() -> Element {
     Some(mut paths) => {
       paths.sort();
       return rsx! {
+        Link { class: "no-print", to: Route::NewPathPage {}, "New path" }
         PathPanelList { paths }
       };
     },
@@ -29,6 +34,7 @@ pub fn SinglePath(id: String) -> Element {
       return rsx! {
         div {
           class: "column gap-large",
+          Link { class: "no-print", to: Route::EditPathPage { id: id.clone() }, "Edit path" }
           PathPanel { path }
         }
       }
@@ -40,3 +46,39 @@ pub fn SinglePath(id: String) -> Element {
     },
   }
 }
+
+#[component]
+pub fn NewPathPage() -> Element {
+  if let Some(elements) = status_element_paths_skills_keywords() {
+    return elements;
+  }
+  let path = Path {
+    id: Default::default(),
+    tier: Tier::Initiate,
+    title: String::new(),
+    summary: None,
+    skill_ids: None,
+    inherient: None,
+    order: None,
+    selections: None,
+  };
+  return rsx! {
+    PathEditor { path, is_new: true }
+  };
+}
+
+#[component]
+pub fn EditPathPage(id: String) -> Element {
+  if let Some(elements) = status_element_paths_skills_keywords() {
+    return elements;
+  }
+  let PathCache(ref path_cache) = use_context();
+  let Some(path) = path_cache.from_id(&id) else {
+    return rsx! {
+      div { "Path not found" }
+    };
+  };
+  return rsx! {
+    PathEditor { key: "{id}", path }
+  };
+}
@@ -8,4 +8,4 @@ pub use builder::CharacterBuilder;
 pub use navbar::Navbar;
 pub use rulebook::MainRules;
 // pub use sheet::{CharacterSheetPage, SingleChracterSheet};
-pub use skill::{SingleSkillPage, SkillsPage};
+pub use skill::{EditSkillPage, NewSkillPage, SingleSkillPage, SkillsPage};
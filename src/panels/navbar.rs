@@ -9,8 +9,10 @@ pub fn Navbar() -> Element {
       Link { to: Route::MainRules {}, "Home" }
       Link { to: Route::PathsPage {}, "Paths" }
       Link { to: Route::SkillsPage {}, "Skills" }
+      Link { to: Route::EquipmentPage {}, "Equipment" }
       Link { to: Route::CharacterBuilder {}, "Builder" }
       Link { to: Route::CharacterSheetsPage {}, "Sheets" }
+      Link { to: Route::ContentPackPage {}, "Admin" }
     }
     Outlet::<Route> {}
   }
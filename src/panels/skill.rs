@@ -3,6 +3,9 @@ use crate::keyword::prelude::*;
 use crate::path::components::*;
 use crate::server::prelude::*;
 use crate::skill::component::*;
+use crate::skill::editor::SkillEditor;
+use crate::skill::prelude::*;
+use crate::Route;
 use dioxus::prelude::*;
 
 #[component]
@@ -25,6 +28,7 @@ pub fn SingleSkillPage(id: String) -> Element {
   return rsx! {
     div {
       class: "column gap-medium",
+      Link { class: "no-print", to: Route::EditSkillPage { id: id.clone() }, "Edit skill" }
       SkillCard { skill }
       if path_ids.len() > 0 {
         PathChipsCard {
@@ -48,8 +52,39 @@ pub fn SkillsPage() -> Element {
   let mut skills = skill_cache.into_vec();
   skills.sort();
   return rsx! {
+    Link { class: "no-print", to: Route::NewSkillPage {}, "New skill" }
     StaggeredGrid {
       SkillCardElements { skills, display: TermDisplay::Embeded, title_as_link: true, include_path_chips: true }
     }
   }
 }
+
+#[component]
+pub fn NewSkillPage() -> Element {
+  if let Some(elements) = status_element_paths_skills_keywords() {
+    return elements;
+  }
+  let skill = Skill {
+    title: String::new(),
+    ..Default::default()
+  };
+  return rsx! {
+    SkillEditor { skill, is_new: true }
+  };
+}
+
+#[component]
+pub fn EditSkillPage(id: String) -> Element {
+  if let Some(elements) = status_element_paths_skills_keywords() {
+    return elements;
+  }
+  let SkillCache(ref skill_cache) = use_context();
+  let Some(skill) = skill_cache.from_id(&id) else {
+    return rsx! {
+      div { "Cannot find skill with id: {id}" }
+    };
+  };
+  return rsx! {
+    SkillEditor { key: "{id}", skill }
+  };
+}
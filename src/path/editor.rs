@@ -0,0 +1,121 @@
+use dioxus::prelude::*;
+
+use super::components::PathPanel;
+use super::prelude::*;
+use crate::common::*;
+use crate::rules::prelude::*;
+use crate::server::prelude::*;
+use crate::skill::prelude::*;
+use crate::Route;
+
+#[component]
+pub fn PathEditor(path: Path, #[props(default)] is_new: bool) -> Element {
+  let PathCache(path_cache) = use_context::<PathCache>();
+  let mut draft: Signal<Path> = use_signal(|| path.clone());
+  let mut status: Signal<SaveStatus> = use_signal(|| SaveStatus::Idle);
+  let nav = navigator();
+  let current = draft();
+  let valid = !current.title.trim().is_empty();
+  let saving = status() == SaveStatus::Saving;
+  let save_class = if saving || !valid { "disabled" } else { "" };
+  let selections = current.selections.clone().unwrap_or_default();
+  rsx! {
+    div {
+      class: "column gap-large",
+      div {
+        class: "card grid dim-keywords no-print",
+        FormRow {
+          title: "Title",
+          TextField { value: current.title.clone(), onchange: move |value| draft.write().title = value }
+        }
+        FormRow {
+          title: "Summary",
+          OptionalTextField {
+            value: current.summary.clone(),
+            multiline: true,
+            onchange: move |value| draft.write().summary = value,
+          }
+        }
+        FormRow {
+          title: "Tier",
+          EnumSelect {
+            options: Tier::ordered(),
+            selected: Some(current.tier.clone()),
+            onchange: move |value: Option<Tier>| if let Some(tier) = value { draft.write().tier = tier },
+          }
+          "Inherient"
+          OptionalFlagField { value: current.inherient, onchange: move |value| draft.write().inherient = value }
+        }
+        FormRow {
+          title: "Order",
+          OptionalNumberField {
+            value: current.order.as_ref().map(|order| order.category),
+            onchange: move |value: Option<i32>| {
+              draft.write().order = value.map(|category| RelicOrdering { category });
+            },
+          }
+        }
+        FormRow {
+          title: "Skills",
+          SkillPicker {
+            selected: current.skill_ids.clone().unwrap_or_default(),
+            onchange: move |value: Vec<_>| {
+              draft.write().skill_ids = if value.is_empty() { None } else { Some(value) };
+            },
+          }
+        }
+        div { class: "uv-full subheading", "Selections" }
+        for filter in SkillFilter::ordered() {
+          FormRow {
+            title: filter.to_string(),
+            NumberField {
+              value: selections.get(&filter).cloned().unwrap_or_default(),
+              onchange: move |ranks: i32| {
+                let mut path = draft.write();
+                let mut selections = path.selections.clone().unwrap_or_default();
+                match ranks > 0 {
+                  true => selections.insert(filter.clone(), ranks),
+                  false => selections.remove(&filter),
+                };
+                path.selections = if selections.is_empty() { None } else { Some(selections) };
+              },
+            }
+          }
+        }
+        div {
+          class: "uv-full row gap-large spacer",
+          div {
+            class: "next-button {save_class}",
+            onclick: move |_| {
+              if saving || !valid {
+                return;
+              }
+              let path = draft();
+              let cache = path_cache.clone();
+              status.set(SaveStatus::Saving);
+              spawn(async move {
+                let result = match is_new {
+                  true => insert_path(path).await,
+                  false => update_path(path).await,
+                };
+                match result {
+                  Ok(id) => {
+                    status.set(SaveStatus::Idle);
+                    cache.restart();
+                    nav.push(Route::SinglePath { id });
+                  },
+                  Err(error) => status.set(SaveStatus::Errored(error.to_string())),
+                }
+              });
+            },
+            if saving { "Saving ..." } else { "Save path" }
+          }
+        }
+        if let SaveStatus::Errored(error) = status() {
+          div { class: "uv-full errored", "Unable to save path: {error}" }
+        }
+      }
+      PathPanel { path: current }
+    }
+  }
+}
@@ -1,4 +1,5 @@
 pub mod components;
+pub mod editor;
 mod selection;
 
 use bson::oid::ObjectId;
@@ -79,7 +80,79 @@ impl Ord for Path {
   }
 }
 
+#[cfg(any(feature = "server", test))]
+impl Path {
+  // Skills whose `paths` back-links disagree with `skill_ids`, updated to match this path.
+  pub fn relinked_skills(&self, skills: &HashMap<String, Skill>) -> Vec<Skill> {
+    let listed = self.skill_ids.clone().unwrap_or_default();
+    let mut relinked: Vec<Skill> = skills
+      .values()
+      .filter_map(|skill| {
+        let linked = skill.paths.as_ref().is_some_and(|paths| paths.contains(&self.id));
+        if listed.contains(&skill.id) == linked {
+          return None;
+        }
+        let mut skill = skill.clone();
+        let mut paths = skill.paths.take().unwrap_or_default();
+        if linked {
+          paths.remove(&self.id);
+        } else {
+          paths.insert(self.id);
+        }
+        skill.paths = if paths.is_empty() { None } else { Some(paths) };
+        Some(skill)
+      })
+      .collect();
+    relinked.sort_by_key(|skill| skill.id);
+    return relinked;
+  }
+}
+
 pub mod prelude {
   pub use super::Path;
   pub use super::selection::{Constraint, PathFilter, SelectionFilter, SkillFilter};
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::HashSet;
+
+  fn skill(paths: &[ObjectId]) -> Skill {
+    let paths: HashSet<ObjectId> = paths.iter().cloned().collect();
+    return Skill {
+      paths: if paths.is_empty() { None } else { Some(paths) },
+      ..Skill::default()
+    };
+  }
+
+  #[test]
+  fn relinks_added_and_removed_skills() {
+    let path_id = ObjectId::new();
+    let other_path = ObjectId::new();
+    let kept = skill(&[path_id]);
+    let added = skill(&[other_path]);
+    let removed = skill(&[path_id]);
+    let unrelated = skill(&[]);
+    let path = Path {
+      id: path_id,
+      tier: Tier::Initiate,
+      title: "Warden".into(),
+      summary: None,
+      skill_ids: Some(vec![kept.id, added.id]),
+      inherient: None,
+      order: None,
+      selections: None,
+    };
+    let skills: HashMap<String, Skill> = [&kept, &added, &removed, &unrelated]
+      .into_iter()
+      .map(|skill| (skill.id.to_hex(), skill.clone()))
+      .collect();
+    let relinked = path.relinked_skills(&skills);
+    let find = |id: ObjectId| relinked.iter().find(|skill| skill.id == id).cloned();
+    assert_eq!(relinked.len(), 2);
+    assert_eq!(find(added.id).and_then(|skill| skill.paths), Some([other_path, path_id].into()));
+    assert_eq!(find(removed.id).and_then(|skill| skill.paths), None);
+    assert!(find(removed.id).is_some());
+  }
+}
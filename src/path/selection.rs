@@ -47,6 +47,10 @@ impl Display for SkillFilter {
 }
 
 impl SkillFilter {
+  pub fn ordered() -> Vec<SkillFilter> {
+    return vec![Features, CoreFeatures, MinorFeatures, CoreMinorFeatures, Cantrips, Spells];
+  }
+
   pub fn weight(&self) -> i32 {
     match self {
       Features | CoreFeatures | Spells => 2,
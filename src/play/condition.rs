@@ -0,0 +1,140 @@
+use bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+
+use crate::skill::prelude::{Duration, DurationClass};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expiry {
+  // The tracker advances whole rounds, so round expiries land on the start or end of a round
+  RoundStart(i32),
+  RoundEnd(i32),
+  Minute(i32),
+  Manual,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveCondition {
+  pub keyword_id: ObjectId,
+  pub source_skill_id: Option<ObjectId>,
+  pub duration: Duration,
+  pub applied_round: i32,
+  pub applied_minute: i32,
+}
+
+fn minutes_per(class: &DurationClass) -> Option<i32> {
+  return match class {
+    DurationClass::Minutes => Some(1),
+    DurationClass::Hours => Some(60),
+    DurationClass::Days => Some(60 * 24),
+    DurationClass::Weeks => Some(60 * 24 * 7),
+    DurationClass::Months => Some(60 * 24 * 30),
+    _ => None,
+  };
+}
+
+impl Expiry {
+  pub fn from_duration(duration: &Duration, round: i32, minute: i32) -> Self {
+    let length = duration.length.unwrap_or(1);
+    if let Some(minutes) = minutes_per(&duration.class) {
+      return Expiry::Minute(minute + length * minutes);
+    }
+    return match duration.class {
+      DurationClass::NextTurnStart => Expiry::RoundStart(round + 1),
+      DurationClass::NextTurnEnd => Expiry::RoundEnd(round + 1),
+      _ => Expiry::Manual,
+    };
+  }
+
+  pub fn is_expired(&self, round: i32, minute: i32) -> bool {
+    return match self {
+      Expiry::RoundStart(expires) => round >= *expires,
+      Expiry::RoundEnd(expires) => round > *expires,
+      Expiry::Minute(expires) => minute >= *expires,
+      Expiry::Manual => false,
+    };
+  }
+
+  pub fn remaining(&self, round: i32, minute: i32) -> String {
+    return match self {
+      Expiry::RoundStart(expires) => match expires - round {
+        1 => "1 round".into(),
+        rounds => format!("{rounds} rounds"),
+      },
+      Expiry::RoundEnd(expires) if *expires == round => "Until the end of this round".into(),
+      Expiry::RoundEnd(expires) => format!("Until the end of round {expires}"),
+      Expiry::Minute(expires) => match expires - minute {
+        1 => "1 minute".into(),
+        minutes => format!("{minutes} minutes"),
+      },
+      Expiry::Manual => "Until removed".into(),
+    };
+  }
+}
+
+impl ActiveCondition {
+  pub fn expiry(&self) -> Expiry {
+    return Expiry::from_duration(&self.duration, self.applied_round, self.applied_minute);
+  }
+
+  pub fn is_expired(&self, round: i32, minute: i32) -> bool {
+    return self.expiry().is_expired(round, minute);
+  }
+
+  pub fn remaining(&self, round: i32, minute: i32) -> String {
+    return self.expiry().remaining(round, minute);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn expiry(class: DurationClass, length: Option<i32>) -> Expiry {
+    let duration = Duration { class, length, ..Duration::default() };
+    return Expiry::from_duration(&duration, 3, 100);
+  }
+
+  #[test]
+  fn next_turn_start_expires_when_the_next_round_starts() {
+    let expiry = expiry(DurationClass::NextTurnStart, None);
+    assert_eq!(expiry, Expiry::RoundStart(4));
+    assert!(!expiry.is_expired(3, 100));
+    assert!(expiry.is_expired(4, 100));
+  }
+
+  #[test]
+  fn next_turn_end_lasts_through_the_next_round() {
+    let expiry = expiry(DurationClass::NextTurnEnd, None);
+    assert_eq!(expiry, Expiry::RoundEnd(4));
+    assert!(!expiry.is_expired(4, 100));
+    assert_eq!(expiry.remaining(4, 100), "Until the end of this round");
+    assert!(expiry.is_expired(5, 100));
+  }
+
+  #[test]
+  fn timed_durations_expire_by_minute() {
+    let cases = [
+      (DurationClass::Minutes, 1),
+      (DurationClass::Hours, 60),
+      (DurationClass::Days, 60 * 24),
+      (DurationClass::Weeks, 60 * 24 * 7),
+      (DurationClass::Months, 60 * 24 * 30),
+    ];
+    for (class, minutes) in cases {
+      let expiry = expiry(class, Some(2));
+      assert_eq!(expiry, Expiry::Minute(100 + 2 * minutes));
+      assert!(!expiry.is_expired(3, 99 + 2 * minutes));
+      assert!(expiry.is_expired(3, 100 + 2 * minutes));
+    }
+  }
+
+  #[test]
+  fn other_durations_last_until_removed() {
+    for class in [DurationClass::Custom, DurationClass::WhileReserved] {
+      let expiry = expiry(class, None);
+      assert_eq!(expiry, Expiry::Manual);
+      assert!(!expiry.is_expired(i32::MAX, i32::MAX));
+    }
+  }
+}
@@ -0,0 +1,172 @@
+use bson::oid::ObjectId;
+use dioxus::prelude::*;
+
+use super::condition::ActiveCondition;
+use super::PlayState;
+use crate::common::*;
+use crate::keyword::prelude::*;
+use crate::server::prelude::*;
+use crate::skill::prelude::*;
+
+#[component]
+pub fn ConditionTracker(
+  state: PlayState, skills: Vec<Skill>, onchange: EventHandler<PlayState>,
+) -> Element {
+  let mut condition: Signal<Option<ObjectId>> = use_signal(|| None);
+  let mut source: Signal<Option<ObjectId>> = use_signal(|| None);
+  let mut duration: Signal<Duration> = use_signal(Duration::default);
+  let mut minutes: Signal<i32> = use_signal(|| 1);
+  let mut expired: Signal<Vec<String>> = use_signal(Vec::new);
+  let KeywordCache(ref keyword_cache) = use_context::<KeywordCache>();
+  let SkillCache(ref skill_cache) = use_context::<SkillCache>();
+  let mut conditions: Vec<Keyword> = keyword_cache
+    .into_vec()
+    .into_iter()
+    .filter(|keyword| keyword.class == KeywordClass::Condition)
+    .collect();
+  conditions.sort();
+  let condition_ids: Vec<ObjectId> = conditions.iter().map(|keyword| keyword.id).collect();
+  let condition_labels: Vec<String> =
+    conditions.iter().map(|keyword| keyword.title.clone()).collect();
+  let mut sources: Vec<Skill> = Vec::new();
+  for skill in skills {
+    if !sources.iter().any(|source| source.id == skill.id) {
+      sources.push(skill);
+    }
+  }
+  let source_ids: Vec<ObjectId> = sources.iter().map(|skill| skill.id).collect();
+  let source_labels: Vec<String> = sources.iter().map(|skill| skill.title.clone()).collect();
+  let current_duration = duration();
+  let apply_class = if condition().is_none() { "disabled" } else { "" };
+  let round = state.round;
+  let minute = state.minute;
+  let expired_titles = expired().join(", ");
+
+  let titles = |expired: Vec<ActiveCondition>| -> Vec<String> {
+    return expired
+      .iter()
+      .filter_map(|condition| keyword_cache.from_object_id(&condition.keyword_id))
+      .map(|keyword| keyword.title)
+      .collect();
+  };
+  let mut next_round = state.clone();
+  let round_expired = titles(next_round.advance(1, 0));
+  let mut later = state.clone();
+  let time_expired = titles(later.advance(0, minutes()));
+  let apply_state = state.clone();
+  let active_conditions: Vec<(usize, ActiveCondition, Keyword, Option<String>)> = state
+    .conditions
+    .iter()
+    .enumerate()
+    .filter_map(|(index, active)| {
+      let keyword = keyword_cache.from_object_id(&active.keyword_id)?;
+      let source = active.source_skill_id.and_then(|id| skill_cache.from_object_id(&id));
+      Some((index, active.clone(), keyword, source.map(|skill| skill.title)))
+    })
+    .collect();
+  rsx! {
+    div { class: "uv-full subheading", "Conditions" }
+    FormRow {
+      title: "Round {round}",
+      div {
+        class: "next-button",
+        onclick: move |_| {
+          expired.set(round_expired.clone());
+          onchange.call(next_round.clone());
+        },
+        "Next round"
+      }
+    }
+    FormRow {
+      title: "Minute {minute}",
+      NumberField { value: minutes(), onchange: move |value: i32| minutes.set(value.max(0)) }
+      div {
+        class: "next-button",
+        onclick: move |_| {
+          expired.set(time_expired.clone());
+          onchange.call(later.clone());
+        },
+        "Advance time"
+      }
+    }
+    if !expired_titles.is_empty() {
+      div { class: "uv-full italics", "Expired: {expired_titles}" }
+    }
+    FormRow {
+      title: "Apply",
+      EnumSelect {
+        options: condition_ids,
+        labels: condition_labels,
+        selected: condition(),
+        onchange: move |value| condition.set(value),
+      }
+      EnumSelect {
+        options: source_ids,
+        labels: source_labels,
+        selected: source(),
+        optional: true,
+        onchange: move |value: Option<ObjectId>| {
+          let skill = sources.iter().find(|skill| Some(skill.id) == value);
+          if let Some(skill_duration) = skill.and_then(|skill| skill.action.duration.clone()) {
+            duration.set(skill_duration);
+          }
+          source.set(value);
+        },
+      }
+    }
+    FormRow {
+      title: "Duration",
+      EnumSelect {
+        options: DurationClass::ordered(),
+        selected: Some(current_duration.class.clone()),
+        onchange: move |value: Option<DurationClass>| {
+          if let Some(class) = value {
+            duration.write().class = class;
+          }
+        },
+      }
+      OptionalNumberField {
+        value: current_duration.length,
+        onchange: move |value| duration.write().length = value,
+      }
+      div {
+        class: "next-button {apply_class}",
+        onclick: move |_| {
+          let Some(keyword_id) = condition() else {
+            return;
+          };
+          let mut updated = apply_state.clone();
+          updated.apply_condition(keyword_id, source(), duration());
+          onchange.call(updated);
+        },
+        "Apply"
+      }
+    }
+    for (index, active, keyword, source_title) in active_conditions {
+      div {
+        class: "uv-full row align-start",
+        KeywordCard { keyword }
+        div {
+          class: "column",
+          if let Some(title) = source_title {
+            div { "From {title}" }
+          }
+          div { "{active.duration}" }
+          div { class: "highlight", "{active.remaining(round, minute)}" }
+          div {
+            class: "chip",
+            onclick: {
+              let state = state.clone();
+              move |_| {
+                let mut updated = state.clone();
+                updated.conditions.remove(index);
+                onchange.call(updated);
+              }
+            },
+            "Remove ✕"
+          }
+        }
+      }
+    }
+  }
+}
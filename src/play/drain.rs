@@ -0,0 +1,121 @@
+use std::fmt;
+
+use rand::Rng;
+
+use super::PoolUsage;
+use crate::skill::prelude::ResourcePool;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DrainOutcome {
+  pub resource: ResourcePool,
+  pub spent: i32,
+  pub rolls: Vec<i32>,
+  pub from_reserves: i32,
+  pub from_pool: i32,
+}
+
+impl fmt::Display for DrainOutcome {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let rolls = self.rolls.iter().map(|roll| roll.to_string()).collect::<Vec<String>>();
+    write!(
+      f,
+      "Spent {} {} rolling {} ({}): ",
+      self.spent,
+      self.resource,
+      self.resource.drain(),
+      rolls.join(", ")
+    )?;
+    return match (self.from_reserves, self.from_pool) {
+      (0, 0) => write!(f, "no drain"),
+      (reserves, 0) => write!(f, "{reserves} drain from reserves"),
+      (0, pool) => write!(f, "{pool} drain from the pool"),
+      (reserves, pool) => write!(f, "{reserves} drain from reserves and {pool} from the pool"),
+    };
+  }
+}
+
+pub fn resolve_drain(
+  usage: &mut PoolUsage, pool: i32, flow: i32, amount: i32, rng: &mut impl Rng,
+) -> DrainOutcome {
+  let die = usage.resource.drain();
+  let amount = amount.max(0);
+  let rolls: Vec<i32> = (0..amount).map(|_| rng.random_range(1..=die.sides)).collect();
+  let drain: i32 = rolls.iter().map(|roll| die.drain_for(*roll)).sum();
+  let reserves = (pool - flow - usage.drained).max(0);
+  let from_reserves = drain.min(reserves);
+  let from_pool = (drain - from_reserves).min((pool - usage.drained - from_reserves).max(0));
+  usage.spent += amount;
+  usage.drained += from_reserves + from_pool;
+  return DrainOutcome {
+    resource: usage.resource.clone(),
+    spent: amount,
+    rolls,
+    from_reserves,
+    from_pool,
+  };
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::rules::prelude::seeded_rng;
+
+  fn usage(resource: ResourcePool, drained: i32) -> PoolUsage {
+    return PoolUsage { resource, spent: 0, reserved: 0, drained };
+  }
+
+  fn drain_count(outcome: &DrainOutcome, drains_on: i32) -> i32 {
+    return outcome.rolls.iter().filter(|roll| **roll <= drains_on).count() as i32;
+  }
+
+  #[test]
+  fn mana_tiers_drain_on_wider_thresholds() {
+    let tiers = [
+      (ResourcePool::MinorMana, 2),
+      (ResourcePool::ModerateMana, 3),
+      (ResourcePool::MajorMana, 4),
+    ];
+    for (resource, drains_on) in tiers {
+      let die = resource.drain();
+      assert_eq!(die.sides, 10);
+      assert_eq!(die.drain_for(drains_on), 1);
+      assert_eq!(die.drain_for(drains_on + 1), 0);
+      let mut usage = usage(resource, 0);
+      let outcome = resolve_drain(&mut usage, 1000, 0, 50, &mut seeded_rng(5));
+      assert_eq!(outcome.rolls.len(), 50);
+      assert!(outcome.rolls.iter().all(|roll| (1..=10).contains(roll)));
+      assert_eq!(outcome.from_reserves, drain_count(&outcome, drains_on));
+      assert_eq!(outcome.from_pool, 0);
+      assert_eq!(usage.spent, 50);
+    }
+  }
+
+  #[test]
+  fn drains_reserves_before_the_pool() {
+    let mut usage = usage(ResourcePool::MajorMana, 0);
+    let outcome = resolve_drain(&mut usage, 10, 4, 40, &mut seeded_rng(11));
+    let drain = drain_count(&outcome, 4);
+    assert!(drain > 6, "seed should drain past the reserves, drained {drain}");
+    assert_eq!(outcome.from_reserves, 6);
+    assert_eq!(outcome.from_pool, (drain - 6).min(4));
+    assert_eq!(usage.drained, outcome.from_reserves + outcome.from_pool);
+  }
+
+  #[test]
+  fn drains_the_pool_once_reserves_are_gone() {
+    let mut usage = usage(ResourcePool::Anointment, 6);
+    let outcome = resolve_drain(&mut usage, 10, 4, 6, &mut seeded_rng(3));
+    let drain = drain_count(&outcome, 2);
+    assert!(drain > 0, "seed should roll at least one drain");
+    assert_eq!(outcome.from_reserves, 0);
+    assert_eq!(outcome.from_pool, drain.min(4));
+    assert_eq!(usage.drained, 6 + outcome.from_pool);
+  }
+
+  #[test]
+  fn same_seed_rolls_the_same_drain() {
+    let first = resolve_drain(&mut usage(ResourcePool::Rage, 0), 8, 2, 5, &mut seeded_rng(9));
+    let second = resolve_drain(&mut usage(ResourcePool::Rage, 0), 8, 2, 5, &mut seeded_rng(9));
+    assert_eq!(first, second);
+  }
+}
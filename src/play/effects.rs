@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use bson::oid::ObjectId;
+use dioxus::prelude::*;
+
+use super::drain::DrainOutcome;
+use super::sustain::{PoolLimit, SustainedEffect};
+use super::PlayState;
+use crate::common::*;
+use crate::server::prelude::*;
+use crate::skill::prelude::*;
+
+#[component]
+pub fn SustainedEffects(
+  state: PlayState, skills: Vec<Skill>, limits: HashMap<ResourcePool, PoolLimit>,
+  log: Vec<(ObjectId, String)>, onchange: EventHandler<PlayState>,
+) -> Element {
+  let mut selected: Signal<Option<ObjectId>> = use_signal(|| None);
+  let mut outcome: Signal<Result<Option<DrainOutcome>, String>> = use_signal(|| Ok(None));
+  let SkillCache(ref skill_cache) = use_context::<SkillCache>();
+  let mut sustained: Vec<Skill> = Vec::new();
+  for skill in skills {
+    let is_sustained = skill.action.duration.as_ref().is_some_and(SustainedEffect::is_sustained);
+    if is_sustained && !sustained.iter().any(|existing| existing.id == skill.id) {
+      sustained.push(skill);
+    }
+  }
+  let skill_ids: Vec<ObjectId> = sustained.iter().map(|skill| skill.id).collect();
+  let skill_labels: Vec<String> = sustained.iter().map(|skill| skill.title.clone()).collect();
+  let title = move |skill_id: &ObjectId| -> String {
+    return skill_cache.from_object_id(skill_id).map(|skill| skill.title).unwrap_or_default();
+  };
+  let effects: Vec<(usize, String, String, String)> = state
+    .effects
+    .iter()
+    .enumerate()
+    .map(|(index, effect)| {
+      let remaining = effect.expiry().remaining(state.round, state.minute);
+      (index, title(&effect.skill_id), effect.summary(), remaining)
+    })
+    .collect();
+  let log: Vec<String> =
+    log.iter().map(|(skill_id, message)| format!("{}: {message}", title(skill_id))).collect();
+  let activate_class = if selected().is_none() { "disabled" } else { "" };
+  let activate_state = state.clone();
+  rsx! {
+    div { class: "uv-full subheading", "Sustained effects" }
+    if !sustained.is_empty() {
+      FormRow {
+        title: "Activate",
+        EnumSelect {
+          options: skill_ids,
+          labels: skill_labels,
+          selected: selected(),
+          onchange: move |value| selected.set(value),
+        }
+        div {
+          class: "next-button {activate_class}",
+          onclick: move |_| {
+            let Some(skill) = sustained.iter().find(|skill| Some(skill.id) == selected()) else {
+              return;
+            };
+            let mut updated = activate_state.clone();
+            let result = updated.activate(skill, &limits, &mut rand::rng());
+            if result.is_ok() {
+              onchange.call(updated);
+            }
+            outcome.set(result);
+          },
+          "Activate"
+        }
+        match outcome() {
+          Ok(Some(result)) => rsx! { div { "{result}" } },
+          Ok(None) => rsx! {},
+          Err(error) => rsx! { div { class: "errored", "{error}" } },
+        }
+      }
+    }
+    for message in log {
+      div { class: "uv-full italics", "{message}" }
+    }
+    for (index, title, summary, remaining) in effects {
+      FormRow {
+        title,
+        div { "{summary}" }
+        div { class: "highlight", "{remaining}" }
+        div {
+          class: "chip",
+          onclick: {
+            let state = state.clone();
+            move |_| {
+              let mut updated = state.clone();
+              updated.release(index);
+              onchange.call(updated);
+            }
+          },
+          "Release ✕"
+        }
+      }
+    }
+  }
+}
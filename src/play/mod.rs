@@ -0,0 +1,354 @@
+mod condition;
+mod conditions;
+mod drain;
+mod effects;
+mod roller;
+mod sustain;
+mod table;
+mod tracker;
+
+use std::collections::HashMap;
+
+use bson::oid::ObjectId;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::character::prelude::CharacterAttribute;
+use crate::skill::prelude::{Duration, ResourcePool, Skill};
+
+use condition::ActiveCondition;
+use drain::{resolve_drain, DrainOutcome};
+use sustain::{PoolLimit, SustainedEffect};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolUsage {
+  pub resource: ResourcePool,
+  pub spent: i32,
+  pub reserved: i32,
+  #[serde(default)]
+  pub drained: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TemporaryModifier {
+  pub label: String,
+  pub target: Option<CharacterAttribute>,
+  pub value: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayState {
+  #[serde(rename = "_id")]
+  pub id: ObjectId,
+  pub character_id: ObjectId,
+  pub session: String,
+  #[serde(default)]
+  pub campaign: Option<String>,
+  pub damage: i32,
+  pub constitution_spent: i32,
+  pub pools: Vec<PoolUsage>,
+  pub modifiers: Vec<TemporaryModifier>,
+  #[serde(default)]
+  pub round: i32,
+  #[serde(default)]
+  pub minute: i32,
+  #[serde(default)]
+  pub conditions: Vec<ActiveCondition>,
+  #[serde(default)]
+  pub effects: Vec<SustainedEffect>,
+  pub revision: Option<i64>,
+}
+
+impl PlayState {
+  #[cfg(any(feature = "server", test))]
+  pub fn new(character_id: ObjectId, session: String) -> Self {
+    return Self {
+      id: ObjectId::new(),
+      character_id,
+      session,
+      campaign: None,
+      damage: 0,
+      constitution_spent: 0,
+      pools: Vec::new(),
+      modifiers: Vec::new(),
+      round: 0,
+      minute: 0,
+      conditions: Vec::new(),
+      effects: Vec::new(),
+      revision: None,
+    };
+  }
+
+  pub fn pool(&self, resource: &ResourcePool) -> PoolUsage {
+    return self
+      .pools
+      .iter()
+      .find(|usage| &usage.resource == resource)
+      .cloned()
+      .unwrap_or(PoolUsage { resource: resource.clone(), spent: 0, reserved: 0, drained: 0 });
+  }
+
+  pub fn set_pool(&mut self, usage: PoolUsage) {
+    self.pools.retain(|existing| existing.resource != usage.resource);
+    if usage.spent != 0 || usage.reserved != 0 || usage.drained != 0 {
+      self.pools.push(usage);
+    }
+  }
+
+  pub fn available(&self, resource: &ResourcePool, limit: &PoolLimit) -> i32 {
+    let usage = self.pool(resource);
+    return limit.pool - usage.drained - usage.spent - usage.reserved;
+  }
+
+  fn pay(
+    &mut self, resource: &ResourcePool, amount: i32, limits: &HashMap<ResourcePool, PoolLimit>,
+    rng: &mut impl Rng,
+  ) -> Result<DrainOutcome, String> {
+    let limit = limits.get(resource).ok_or(format!("No {resource} pool is available"))?;
+    let available = self.available(resource, limit);
+    if amount > available {
+      return Err(format!("{amount} {resource} is needed but only {available} is available"));
+    }
+    let mut usage = self.pool(resource);
+    let outcome = resolve_drain(&mut usage, limit.pool, limit.flow, amount, rng);
+    self.set_pool(usage);
+    return Ok(outcome);
+  }
+
+  pub fn activate(
+    &mut self, skill: &Skill, limits: &HashMap<ResourcePool, PoolLimit>, rng: &mut impl Rng,
+  ) -> Result<Option<DrainOutcome>, String> {
+    let action = &skill.action;
+    let effect = action
+      .duration
+      .as_ref()
+      .and_then(|duration| {
+        SustainedEffect::new(skill.id, &action.cost, duration, self.round, self.minute)
+      })
+      .ok_or(format!("{} is not a sustained skill with a resource cost", skill.title))?;
+    if effect.reserved > 0 {
+      let limit = limits
+        .get(&effect.resource)
+        .ok_or(format!("No {} pool is available", effect.resource))?;
+      let available = self.available(&effect.resource, limit);
+      if effect.reserved > available {
+        return Err(format!(
+          "{} {} must be reserved but only {available} is available",
+          effect.reserved, effect.resource
+        ));
+      }
+      let mut usage = self.pool(&effect.resource);
+      usage.reserved += effect.reserved;
+      self.set_pool(usage);
+      self.effects.push(effect);
+      return Ok(None);
+    }
+    let outcome = match &action.cost {
+      Some(cost) => Some(self.pay(&cost.resource, cost.minimum_resource_cost(), limits, rng)?),
+      None => None,
+    };
+    self.effects.push(effect);
+    return Ok(outcome);
+  }
+
+  pub fn release(&mut self, index: usize) {
+    if index >= self.effects.len() {
+      return;
+    }
+    let effect = self.effects.remove(index);
+    if effect.reserved > 0 {
+      let mut usage = self.pool(&effect.resource);
+      usage.reserved = (usage.reserved - effect.reserved).max(0);
+      self.set_pool(usage);
+    }
+  }
+
+  pub fn pay_upkeep(
+    &mut self, limits: &HashMap<ResourcePool, PoolLimit>, rng: &mut impl Rng,
+  ) -> Vec<(ObjectId, String)> {
+    let mut log: Vec<(ObjectId, String)> = Vec::new();
+    let (round, minute) = (self.round, self.minute);
+    for mut effect in std::mem::take(&mut self.effects) {
+      if !effect.upkeep_due(round, minute) {
+        self.effects.push(effect);
+        continue;
+      }
+      match self.pay(&effect.resource, effect.upkeep, limits, rng) {
+        Ok(outcome) => {
+          log.push((effect.skill_id, format!("Upkeep paid. {outcome}")));
+          effect.paid_round = round;
+          effect.paid_minute = minute;
+          self.effects.push(effect);
+        },
+        Err(error) => log.push((effect.skill_id, format!("Ended, upkeep unpaid. {error}"))),
+      }
+    }
+    for (resource, limit) in limits {
+      loop {
+        let usage = self.pool(resource);
+        if usage.reserved <= limit.pool - usage.drained {
+          break;
+        }
+        let Some(index) = self
+          .effects
+          .iter()
+          .rposition(|effect| &effect.resource == resource && effect.reserved > 0)
+        else {
+          break;
+        };
+        log.push((self.effects[index].skill_id, "Ended, reservation exceeds the pool".into()));
+        self.release(index);
+      }
+    }
+    return log;
+  }
+
+  pub fn modifier_total(&self, attribute: &CharacterAttribute) -> i32 {
+    return self
+      .modifiers
+      .iter()
+      .filter(|modifier| modifier.target.as_ref() == Some(attribute))
+      .map(|modifier| modifier.value)
+      .sum();
+  }
+
+  pub fn apply_condition(
+    &mut self, keyword_id: ObjectId, source_skill_id: Option<ObjectId>, duration: Duration,
+  ) {
+    self.conditions.push(ActiveCondition {
+      keyword_id,
+      source_skill_id,
+      duration,
+      applied_round: self.round,
+      applied_minute: self.minute,
+    });
+  }
+
+  pub fn advance(&mut self, rounds: i32, minutes: i32) -> Vec<ActiveCondition> {
+    self.round += rounds.max(0);
+    self.minute += minutes.max(0);
+    let (round, minute) = (self.round, self.minute);
+    let (expired, active): (Vec<ActiveCondition>, Vec<ActiveCondition>) =
+      std::mem::take(&mut self.conditions)
+        .into_iter()
+        .partition(|condition| condition.is_expired(round, minute));
+    self.conditions = active;
+    return expired;
+  }
+
+  pub fn validation_errors(&self) -> Vec<String> {
+    let mut errors: Vec<String> = Vec::new();
+    if self.session.trim().is_empty() {
+      errors.push("session is required".into());
+    }
+    if self.damage < 0 {
+      errors.push("damage cannot be negative".into());
+    }
+    if self.round < 0 || self.minute < 0 {
+      errors.push("game time cannot be negative".into());
+    }
+    if self.constitution_spent < 0 {
+      errors.push("spent constitution cannot be negative".into());
+    }
+    for usage in &self.pools {
+      if usage.spent < 0 || usage.reserved < 0 || usage.drained < 0 {
+        errors.push(format!("{} usage cannot be negative", usage.resource));
+      }
+    }
+    return errors;
+  }
+}
+
+pub mod prelude {
+  pub use super::table::{RollLog, RollLogEntry, RollRequest};
+  pub use super::tracker::PlayTracker;
+  pub use super::PlayState;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::rules::prelude::seeded_rng;
+  use crate::skill::prelude::{DurationClass, ResourceCost};
+
+  fn sustained(resource: ResourcePool, cost: i32, class: DurationClass) -> Skill {
+    let mut skill = Skill { title: "Aura".into(), ..Skill::default() };
+    let upkeep = class != DurationClass::WhileReserved;
+    let cost = ResourceCost { resource, base_cost: Some(cost), ..Default::default() };
+    skill.action.cost = Some(cost);
+    skill.action.duration =
+      Some(Duration { class, length: Some(1), upkeep: Some(upkeep), ..Duration::default() });
+    return skill;
+  }
+
+  fn limits(resource: ResourcePool, pool: i32) -> HashMap<ResourcePool, PoolLimit> {
+    return [(resource, PoolLimit { pool, flow: 0 })].into();
+  }
+
+  #[test]
+  fn reserving_moves_the_cost_into_the_pool_reservation() {
+    let mut state = PlayState::new(ObjectId::new(), "Session".into());
+    let skill = sustained(ResourcePool::Anointment, 3, DurationClass::WhileReserved);
+    let limits = limits(ResourcePool::Anointment, 5);
+    let outcome = state.activate(&skill, &limits, &mut seeded_rng(1)).unwrap();
+    assert_eq!(outcome, None);
+    assert_eq!(state.pool(&ResourcePool::Anointment).reserved, 3);
+    assert_eq!(state.pool(&ResourcePool::Anointment).spent, 0);
+    assert!(state.activate(&skill, &limits, &mut seeded_rng(1)).is_err());
+    state.release(0);
+    assert!(state.effects.is_empty());
+    assert!(state.pools.is_empty());
+  }
+
+  #[test]
+  fn upkeep_is_paid_once_per_period() {
+    let mut state = PlayState::new(ObjectId::new(), "Session".into());
+    let skill = sustained(ResourcePool::Mastery, 2, DurationClass::Minutes);
+    let limits = limits(ResourcePool::Mastery, 10);
+    let mut rng = seeded_rng(4);
+    assert!(state.activate(&skill, &limits, &mut rng).unwrap().is_some());
+    assert_eq!(state.pool(&ResourcePool::Mastery).spent, 2);
+    assert!(state.pay_upkeep(&limits, &mut rng).is_empty());
+    state.advance(0, 1);
+    let log = state.pay_upkeep(&limits, &mut rng);
+    assert_eq!(log.len(), 1);
+    assert!(log[0].1.starts_with("Upkeep paid"));
+    assert_eq!(state.pool(&ResourcePool::Mastery).spent, 4);
+    assert_eq!(state.effects[0].paid_minute, 1);
+    assert!(state.pay_upkeep(&limits, &mut rng).is_empty());
+  }
+
+  #[test]
+  fn unpaid_upkeep_ends_the_effect() {
+    let mut state = PlayState::new(ObjectId::new(), "Session".into());
+    let skill = sustained(ResourcePool::Mastery, 2, DurationClass::NextTurnStart);
+    let limits = limits(ResourcePool::Mastery, 3);
+    let mut rng = seeded_rng(8);
+    state.activate(&skill, &limits, &mut rng).unwrap();
+    state.advance(1, 0);
+    let log = state.pay_upkeep(&limits, &mut rng);
+    assert_eq!(log.len(), 1);
+    assert_eq!(log[0].0, skill.id);
+    assert!(log[0].1.starts_with("Ended, upkeep unpaid"));
+    assert!(state.effects.is_empty());
+    assert_eq!(state.pool(&ResourcePool::Mastery).spent, 2);
+  }
+
+  #[test]
+  fn drained_pools_release_reservations_that_no_longer_fit() {
+    let mut state = PlayState::new(ObjectId::new(), "Session".into());
+    let skill = sustained(ResourcePool::Rage, 3, DurationClass::WhileReserved);
+    let limits = limits(ResourcePool::Rage, 4);
+    state.activate(&skill, &limits, &mut seeded_rng(2)).unwrap();
+    let mut usage = state.pool(&ResourcePool::Rage);
+    usage.drained = 2;
+    state.set_pool(usage);
+    let log = state.pay_upkeep(&limits, &mut seeded_rng(2));
+    assert_eq!(log, vec![(skill.id, "Ended, reservation exceeds the pool".to_string())]);
+    assert!(state.effects.is_empty());
+    assert_eq!(state.pool(&ResourcePool::Rage).reserved, 0);
+  }
+}
@@ -0,0 +1,196 @@
+use dioxus::prelude::*;
+
+use crate::character::prelude::*;
+use crate::common::*;
+use crate::equipment::weapon::Weapon;
+use crate::rules::prelude::*;
+
+#[component]
+pub fn CheckRoller(bonuses: Vec<(CharacterAttribute, i32)>) -> Element {
+  let mut attribute: Signal<Option<CharacterAttribute>> = use_signal(|| None);
+  let mut extra: Signal<i32> = use_signal(|| 0);
+  let mut mode: Signal<Modifier> = use_signal(|| Modifier::Normal);
+  let mut class: Signal<RollClass> = use_signal(|| RollClass::Attack);
+  let mut target: Signal<i32> = use_signal(|| 10);
+  let mut seed: Signal<Option<i32>> = use_signal(|| None);
+  let mut roll: Signal<Option<CheckRoll>> = use_signal(|| None);
+  let mut message: Signal<Option<String>> = use_signal(|| None);
+  let options: Vec<CharacterAttribute> = bonuses.iter().map(|(option, _)| option.clone()).collect();
+  let base = match (class(), attribute()) {
+    (RollClass::LuckCheck, _) | (_, None) => 0,
+    (_, Some(selected)) => bonuses
+      .iter()
+      .find(|(option, _)| option == &selected)
+      .map(|(_, bonus)| *bonus)
+      .unwrap_or_default(),
+  };
+  let bonus = base + extra();
+  let current = roll();
+  let outcome = current.as_ref().map(|current| current.result(&class(), target()));
+  rsx! {
+    div { class: "uv-full subheading", "Roll" }
+    FormRow {
+      title: "Check",
+      EnumSelect {
+        options: RollClass::ordered(),
+        selected: Some(class()),
+        onchange: move |value: Option<RollClass>| {
+          if let Some(value) = value {
+            class.set(value);
+          }
+        },
+      }
+      EnumSelect {
+        options,
+        selected: attribute(),
+        optional: true,
+        onchange: move |value| attribute.set(value),
+      }
+      NumberField { value: extra(), onchange: move |value| extra.set(value) }
+      div { class: "highlight", "{bonus:+}" }
+    }
+    FormRow {
+      title: "Against",
+      NumberField { value: target(), onchange: move |value| target.set(value) }
+      EnumSelect {
+        options: Modifier::ordered(),
+        selected: Some(mode()),
+        onchange: move |value: Option<Modifier>| {
+          if let Some(value) = value {
+            mode.set(value);
+          }
+        },
+      }
+      div { "Seed" }
+      OptionalNumberField { value: seed(), onchange: move |value| seed.set(value) }
+      div {
+        class: "next-button",
+        onclick: move |_| {
+          let result = match seed() {
+            Some(seed) => roll_check(bonus, &mode(), &mut seeded_rng(seed as u64)),
+            None => roll_check(bonus, &mode(), &mut rand::rng()),
+          };
+          roll.set(Some(result));
+          message.set(None);
+        },
+        "Roll"
+      }
+    }
+    if let (Some(current), Some(outcome)) = (current, outcome) {
+      FormRow {
+        title: "{outcome}",
+        for (index, die) in current.dice.iter().cloned().enumerate() {
+          div {
+            class: match (die.kept, current.can_reroll(index)) {
+              (true, true) => "chip highlight",
+              (true, false) => "chip highlight disabled",
+              (false, true) => "chip",
+              (false, false) => "chip disabled",
+            },
+            onclick: move |_| {
+              let Some(mut updated) = roll() else {
+                return;
+              };
+              match updated.reroll(index, &mut rand::rng()) {
+                Ok(_) => roll.set(Some(updated)),
+                Err(error) => message.set(Some(error)),
+              }
+            },
+            match die.original {
+              Some(original) => format!("{original} → {}", die.face),
+              None => die.face.to_string(),
+            }
+          }
+        }
+        div { "{current.bonus:+} = {current.total()}" }
+        if let Some(error) = message() {
+          div { class: "errored", "{error}" }
+        }
+      }
+    }
+  }
+}
+
+#[component]
+pub fn DamageRoller(weapons: Vec<Weapon>) -> Element {
+  let mut selected: Signal<Option<usize>> = use_signal(|| None);
+  let mut options: Signal<DamageOptions> = use_signal(DamageOptions::default);
+  let mut resistance: Signal<i32> = use_signal(|| 0);
+  let mut roll: Signal<Option<DamageRoll>> = use_signal(|| None);
+  let current = options();
+  let indices: Vec<usize> = (0..weapons.len()).collect();
+  let labels: Vec<String> = weapons.iter().map(|weapon| weapon.title.clone()).collect();
+  let weapon = selected().and_then(|index| weapons.get(index).cloned());
+  let roll_class = if weapon.is_none() { "disabled" } else { "" };
+  rsx! {
+    FormRow {
+      title: "Damage roll",
+      EnumSelect {
+        options: indices,
+        labels,
+        selected: selected(),
+        onchange: move |value| selected.set(value),
+      }
+      div { "Bonus" }
+      NumberField { value: current.bonus, onchange: move |value| options.write().bonus = value }
+      CheckboxField {
+        checked: current.boosted,
+        onchange: move |checked| options.write().boosted = checked,
+      }
+      div { "Boost" }
+      CheckboxField {
+        checked: current.critical,
+        onchange: move |checked| options.write().critical = checked,
+      }
+      div { "Critical" }
+      div { "Resist" }
+      NumberField { value: resistance(), onchange: move |value| resistance.set(value) }
+      div {
+        class: "next-button {roll_class}",
+        onclick: move |_| {
+          let Some(weapon) = &weapon else {
+            return;
+          };
+          let group = &weapon.damage_dice;
+          let class = &weapon.damage_class;
+          let mut options = options();
+          options.bonus += weapon.damage_modifier.unwrap_or_default();
+          roll.set(Some(roll_damage(group, class, &options, resistance(), &mut rand::rng())));
+        },
+        "Roll"
+      }
+    }
+    if let Some(roll) = roll() {
+      div { class: "uv-full highlight", "{roll}" }
+    }
+  }
+}
+
+#[component]
+pub fn ExpressionRoller() -> Element {
+  let mut text: Signal<String> = use_signal(|| "1d6".to_string());
+  let mut roll: Signal<Option<ExpressionRoll>> = use_signal(|| None);
+  let parsed = text().parse::<DiceExpression>();
+  let roll_class = if parsed.is_err() { "disabled" } else { "" };
+  rsx! {
+    FormRow {
+      title: "Dice roll",
+      TextField { value: text(), onchange: move |value| text.set(value) }
+      div {
+        class: "next-button {roll_class}",
+        onclick: move |_| {
+          if let Ok(expression) = text().parse::<DiceExpression>() {
+            roll.set(Some(roll_expression(&expression, &mut rand::rng())));
+          }
+        },
+        "Roll"
+      }
+      if let Err(error) = parsed {
+        div { class: "errored", "{error}" }
+      }
+    }
+    if let Some(roll) = roll() {
+      div { class: "uv-full highlight", "{roll}" }
+    }
+  }
+}
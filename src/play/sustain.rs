@@ -0,0 +1,67 @@
+use bson::oid::ObjectId;
+use serde::{Deserialize, Serialize};
+
+use super::condition::Expiry;
+use crate::skill::prelude::{Duration, DurationClass, ResourceCost, ResourcePool};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PoolLimit {
+  pub pool: i32,
+  pub flow: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SustainedEffect {
+  pub skill_id: ObjectId,
+  pub resource: ResourcePool,
+  pub duration: Duration,
+  pub reserved: i32,
+  pub upkeep: i32,
+  pub paid_round: i32,
+  pub paid_minute: i32,
+}
+
+impl SustainedEffect {
+  pub fn is_sustained(duration: &Duration) -> bool {
+    return duration.class == DurationClass::WhileReserved || duration.upkeep.unwrap_or_default();
+  }
+
+  pub fn new(
+    skill_id: ObjectId, cost: &Option<ResourceCost>, duration: &Duration, round: i32, minute: i32,
+  ) -> Option<Self> {
+    if !Self::is_sustained(duration) {
+      return None;
+    }
+    let upkeep_cost = duration.upkeep_cost.as_ref().or(cost.as_ref())?;
+    let amount = upkeep_cost.minimum_resource_cost();
+    let reserves = duration.class == DurationClass::WhileReserved;
+    return Some(Self {
+      skill_id,
+      resource: upkeep_cost.resource.clone(),
+      duration: duration.clone(),
+      reserved: if reserves { amount } else { 0 },
+      upkeep: if reserves { 0 } else { amount },
+      paid_round: round,
+      paid_minute: minute,
+    });
+  }
+
+  pub fn expiry(&self) -> Expiry {
+    if self.upkeep == 0 {
+      return Expiry::Manual;
+    }
+    return Expiry::from_duration(&self.duration, self.paid_round, self.paid_minute);
+  }
+
+  pub fn upkeep_due(&self, round: i32, minute: i32) -> bool {
+    return self.expiry().is_expired(round, minute);
+  }
+
+  pub fn summary(&self) -> String {
+    if self.reserved > 0 {
+      return format!("{} {} reserved", self.reserved, self.resource);
+    }
+    return format!("{} {} upkeep", self.upkeep, self.resource.with_drain());
+  }
+}
@@ -0,0 +1,539 @@
+use bson::oid::ObjectId;
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(any(feature = "server", test))]
+use super::PlayState;
+use crate::character::prelude::*;
+use crate::common::*;
+use crate::equipment::weapon::{Weapon, WeaponEntry};
+use crate::rules::prelude::*;
+use crate::server::prelude::{get_roll_log, roll_at_table, stream_roll_log};
+use crate::skill::component::SkillCardElements;
+use crate::skill::prelude::Skill;
+use crate::Route;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum RollSpec {
+  Check(Roll),
+  Attack { weapon_id: ObjectId, capability: Capability },
+}
+
+// The client only says what to roll, the server looks up the sheet for the bonus and damage
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RollRequest {
+  pub character_id: ObjectId,
+  pub session: String,
+  pub spec: RollSpec,
+  pub mode: Modifier,
+  pub target: Option<i32>,
+}
+
+impl RollRequest {
+  pub fn validation_errors(&self) -> Vec<String> {
+    let mut errors: Vec<String> = Vec::new();
+    if self.session.trim().is_empty() {
+      errors.push("session is required".into());
+    }
+    if self.target.is_some_and(|target| target.unsigned_abs() > MAX_MODIFIER.unsigned_abs()) {
+      errors.push(format!("target must be between -{MAX_MODIFIER} and {MAX_MODIFIER}"));
+    }
+    return errors;
+  }
+
+  #[cfg(any(feature = "server", test))]
+  pub fn resolve(
+    &self, campaign: String, sheet: &CharacterSheet, state: &PlayState, skills: &[Skill],
+  ) -> Result<ResolvedRoll, String> {
+    let derived = sheet.derive(skills);
+    let bonus = |capability: &Capability| {
+      let attribute = CharacterAttribute::from(capability.clone());
+      derived.attributes.rank(&attribute).unwrap_or_default() + state.modifier_total(&attribute)
+    };
+    let mut resolved = ResolvedRoll {
+      campaign,
+      roller: sheet.name.clone(),
+      title: String::new(),
+      class: RollClass::Check,
+      bonus: 0,
+      mode: self.mode.clone(),
+      target: self.target,
+      damage: None,
+      damage_class: None,
+    };
+    match &self.spec {
+      RollSpec::Check(roll) => {
+        resolved.bonus = match (&roll.class, &roll.capability) {
+          (RollClass::LuckCheck, _) | (_, None) => 0,
+          (_, Some(capability)) => bonus(capability),
+        };
+        let mut title = roll.class.to_string();
+        if let Some(capability) = &roll.capability {
+          title = format!("{capability} {title}");
+        }
+        if let Some(defense) = &roll.defense {
+          title = format!("{title} vs {defense}");
+        }
+        resolved.title = title;
+        resolved.class = roll.class.clone();
+        resolved.mode = self.mode.combine(&roll.modifier.clone().unwrap_or(Modifier::Normal));
+      },
+      RollSpec::Attack { weapon_id, capability } => {
+        let weapon = sheet
+          .equipped_weapons()
+          .into_iter()
+          .find(|weapon| &weapon.id == weapon_id)
+          .ok_or(format!("{} does not have that weapon equipped", sheet.name))?;
+        resolved.title = format!("{} ({capability})", weapon.title);
+        resolved.class = RollClass::Attack;
+        resolved.bonus = bonus(capability);
+        resolved.damage = Some(weapon.damage());
+        resolved.damage_class = Some(weapon.damage_class);
+      },
+    }
+    return Ok(resolved);
+  }
+}
+
+#[cfg(any(feature = "server", test))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedRoll {
+  pub campaign: String,
+  pub roller: String,
+  pub title: String,
+  pub class: RollClass,
+  pub bonus: i32,
+  pub mode: Modifier,
+  pub target: Option<i32>,
+  pub damage: Option<DiceExpression>,
+  pub damage_class: Option<DamageClass>,
+}
+
+#[cfg(any(feature = "server", test))]
+impl ResolvedRoll {
+  pub fn validation_errors(&self) -> Vec<String> {
+    let mut errors: Vec<String> = Vec::new();
+    if self.bonus.unsigned_abs() > MAX_MODIFIER.unsigned_abs() {
+      errors.push(format!("bonus must be between -{MAX_MODIFIER} and {MAX_MODIFIER}"));
+    }
+    if let Some(error) = self.damage.as_ref().and_then(|damage| damage.limit_error()) {
+      errors.push(error);
+    }
+    if self.damage.is_some() && self.damage_class.is_none() {
+      errors.push("damage needs a damage class".into());
+    }
+    return errors;
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RollLogEntry {
+  #[serde(rename = "_id")]
+  pub id: ObjectId,
+  pub campaign: String,
+  pub roller: String,
+  pub title: String,
+  pub class: RollClass,
+  pub target: Option<i32>,
+  pub check: CheckRoll,
+  pub result: Option<RollResult>,
+  pub damage: Option<DamageRoll>,
+}
+
+impl RollLogEntry {
+  #[cfg(feature = "server")]
+  pub fn resolve(request: ResolvedRoll, rng: &mut impl rand::Rng) -> Self {
+    let check = roll_check(request.bonus, &request.mode, rng);
+    return Self::from_check(request, check, rng);
+  }
+
+  #[cfg(any(feature = "server", test))]
+  fn from_check(request: ResolvedRoll, check: CheckRoll, rng: &mut impl rand::Rng) -> Self {
+    let result = request.target.map(|target| check.result(&request.class, target));
+    let landed = match &result {
+      Some(result) => matches!(result, RollResult::Hit | RollResult::Critical),
+      None => !check.is_botch(),
+    };
+    let critical = match &result {
+      Some(result) => result == &RollResult::Critical,
+      None => check.is_triple() && !check.is_botch(),
+    };
+    let damage = match (&request.damage, &request.damage_class, landed) {
+      (Some(expression), Some(class), true) => {
+        let options = DamageOptions {
+          bonus: expression.modifier,
+          critical,
+          ..DamageOptions::default()
+        };
+        Some(roll_damage(&expression.dice, class, &options, 0, rng))
+      },
+      _ => None,
+    };
+    return Self {
+      id: ObjectId::new(),
+      campaign: request.campaign,
+      roller: request.roller,
+      title: request.title,
+      class: request.class,
+      target: request.target,
+      check,
+      result,
+      damage,
+    };
+  }
+
+  pub fn flags(&self) -> Vec<&'static str> {
+    let mut flags: Vec<&'static str> = Vec::new();
+    let critical = match &self.result {
+      Some(result) => matches!(result, RollResult::Critical | RollResult::CriticalSuccess),
+      None => self.check.is_triple(),
+    };
+    if self.check.is_botch() {
+      flags.push("Botch");
+    } else if critical {
+      flags.push("Critical");
+    }
+    if self.check.mode != Modifier::Normal {
+      flags.push(match self.check.mode {
+        Modifier::Advantage => "Advantage",
+        _ => "Disadvantage",
+      });
+    }
+    return flags;
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct TableRollState {
+  pub character_id: Memo<ObjectId>,
+  pub session: Memo<String>,
+  pub capability: Signal<Capability>,
+  pub target: Signal<Option<i32>>,
+  pub mode: Signal<Modifier>,
+  pub status: Signal<Option<Result<RollLogEntry, String>>>,
+}
+
+impl TableRollState {
+  fn send(&self, spec: RollSpec) {
+    let mut status = self.status;
+    let request = RollRequest {
+      character_id: (self.character_id)(),
+      session: (self.session)(),
+      spec,
+      mode: (self.mode)(),
+      target: (self.target)(),
+    };
+    let errors = request.validation_errors();
+    if !errors.is_empty() {
+      status.set(Some(Err(errors.join(", "))));
+      return;
+    }
+    spawn(async move {
+      match roll_at_table(request).await {
+        Ok(entry) => status.set(Some(Ok(entry))),
+        Err(error) => status.set(Some(Err(error.to_string()))),
+      }
+    });
+  }
+
+  fn attack(&self, weapon_id: ObjectId) {
+    let capability = (self.capability)();
+    self.send(RollSpec::Attack { weapon_id, capability });
+  }
+}
+
+#[component]
+pub fn TableRoller(
+  character_id: ObjectId, session: String, campaign: String, skills: Vec<Skill>,
+  weapons: Vec<Weapon>,
+) -> Element {
+  let character_id = use_memo(use_reactive(&character_id, |character_id| character_id));
+  let session = use_memo(use_reactive(&session, |session| session));
+  let mut capability: Signal<Capability> = use_signal(|| Capability::Warfare);
+  let mut target: Signal<Option<i32>> = use_signal(|| None);
+  let mut mode: Signal<Modifier> = use_signal(|| Modifier::Normal);
+  let status: Signal<Option<Result<RollLogEntry, String>>> = use_signal(|| None);
+  let state = TableRollState { character_id, session, capability, target, mode, status };
+  let on_roll = use_callback(move |roll: TableRoll| match roll {
+    TableRoll::Check(roll) => state.send(RollSpec::Check(roll)),
+    TableRoll::Attack { weapon_id } => state.attack(weapon_id),
+  });
+  use_context_provider(|| TableRollContext { on_roll });
+  rsx! {
+    div {
+      class: "card grid dim-keywords no-print underhang",
+      div { class: "uv-full subheading", "Table roller" }
+      FormRow {
+        title: "Against",
+        OptionalNumberField { value: target(), onchange: move |value| target.set(value) }
+        EnumSelect {
+          options: Modifier::ordered(),
+          selected: Some(mode()),
+          onchange: move |value: Option<Modifier>| {
+            if let Some(value) = value {
+              mode.set(value);
+            }
+          },
+        }
+        Link { class: "chip", to: Route::RollLogPage { campaign: campaign.clone() }, "Open log" }
+      }
+      FormRow {
+        title: "Attack with",
+        EnumSelect {
+          options: Capability::ordered(),
+          selected: Some(capability()),
+          onchange: move |value: Option<Capability>| {
+            if let Some(value) = value {
+              capability.set(value);
+            }
+          },
+        }
+      }
+      match status() {
+        Some(Ok(entry)) => rsx! { RollLogRow { entry } },
+        Some(Err(error)) => rsx! { div { class: "uv-full errored", "{error}" } },
+        None => rsx! {},
+      }
+    }
+    div {
+      class: "row wrap no-print",
+      for weapon in weapons {
+        WeaponEntry { weapon }
+      }
+    }
+    SkillCardElements { skills }
+    RollLog { campaign }
+  }
+}
+
+#[component]
+pub fn RollLog(campaign: String) -> Element {
+  let mut entries: Signal<Vec<RollLogEntry>> = use_signal(Vec::new);
+  let mut error: Signal<Option<String>> = use_signal(|| None);
+  let _feed = use_resource(use_reactive!(|campaign| async move {
+    let mut stream = match stream_roll_log(campaign.clone()).await {
+      Ok(stream) => stream,
+      Err(failure) => {
+        error.set(Some(failure.to_string()));
+        return;
+      },
+    };
+    match get_roll_log(campaign).await {
+      Ok(history) => entries.set(history),
+      Err(failure) => error.set(Some(failure.to_string())),
+    }
+    while let Some(Ok(entry)) = stream.next().await {
+      let mut current = entries.write();
+      if !current.iter().any(|existing| existing.id == entry.id) {
+        current.push(entry);
+      }
+    }
+  }));
+  let current = entries();
+  rsx! {
+    div {
+      class: "card grid dim-keywords underhang",
+      div { class: "uv-full subheading", "Roll log" }
+      if let Some(error) = error() {
+        div { class: "uv-full errored", "{error}" }
+      }
+      if current.is_empty() {
+        div { class: "uv-full", "No rolls yet" }
+      }
+      for entry in current.into_iter().rev() {
+        RollLogRow { key: "{entry.id}", entry }
+      }
+    }
+  }
+}
+
+#[component]
+fn RollLogRow(entry: RollLogEntry) -> Element {
+  let flags = entry.flags();
+  let outcome = match (&entry.result, entry.target) {
+    (Some(result), Some(target)) => format!("{result} against {target}"),
+    _ => format!("{} {}", entry.class, entry.check.total()),
+  };
+  rsx! {
+    FormRow {
+      title: "{entry.roller}: {entry.title}",
+      for die in entry.check.dice.clone() {
+        div {
+          class: if die.kept { "chip highlight" } else { "chip disabled" },
+          match die.original {
+            Some(original) => format!("{original} → {}", die.face),
+            None => die.face.to_string(),
+          }
+        }
+      }
+      div { "{entry.check.bonus:+} = {entry.check.total()}" }
+      for flag in flags {
+        div { class: "chip", "{flag}" }
+      }
+      div { class: "highlight", "{outcome}" }
+      if let Some(damage) = &entry.damage {
+        div { "{damage}" }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::play::TemporaryModifier;
+
+  fn attack(target: i32) -> ResolvedRoll {
+    return ResolvedRoll {
+      campaign: "Campaign".into(),
+      roller: "Roller".into(),
+      title: "Sword".into(),
+      class: RollClass::Attack,
+      bonus: 0,
+      mode: Modifier::Normal,
+      target: Some(target),
+      damage: Some("1d6".parse().unwrap()),
+      damage_class: Some(DamageClass::Slashing),
+    };
+  }
+
+  fn resolve(request: ResolvedRoll, faces: Vec<i32>) -> RollLogEntry {
+    let check = CheckRoll::from_faces(faces, request.bonus, request.mode.clone());
+    return RollLogEntry::from_check(request, check, &mut seeded_rng(3));
+  }
+
+  #[test]
+  fn rejects_oversized_requests() {
+    assert!(attack(10).validation_errors().is_empty());
+    let mut request = attack(10);
+    let mut damage: DiceExpression = "1d12".parse().unwrap();
+    damage.dice[0].amount = i32::MAX;
+    request.damage = Some(damage);
+    assert_eq!(request.validation_errors(), vec!["2147483647 dice is too many, roll at most 100"]);
+    let mut request = attack(10);
+    request.damage = Some(DiceExpression::new(Vec::new(), i32::MIN));
+    assert_eq!(request.validation_errors().len(), 1);
+    let mut request = attack(10);
+    request.bonus = i32::MAX;
+    assert_eq!(request.validation_errors(), vec!["bonus must be between -1000 and 1000"]);
+  }
+
+  #[test]
+  fn failed_triple_hits_without_critical_damage() {
+    let entry = resolve(attack(20), vec![2, 2, 2]);
+    assert_eq!(entry.result, Some(RollResult::Hit));
+    assert!(entry.flags().is_empty());
+    assert_eq!(entry.damage.map(|damage| damage.dice.len()), Some(1));
+  }
+
+  #[test]
+  fn succeeding_triple_adds_a_critical_die() {
+    let entry = resolve(attack(10), vec![4, 4, 4]);
+    assert_eq!(entry.result, Some(RollResult::Critical));
+    assert_eq!(entry.flags(), vec!["Critical"]);
+    assert_eq!(entry.damage.map(|damage| damage.dice.len()), Some(2));
+  }
+
+  #[test]
+  fn untargeted_triples_are_flagged_critical() {
+    let mut request = attack(10);
+    request.target = None;
+    assert_eq!(resolve(request, vec![2, 2, 2]).flags(), vec!["Critical"]);
+  }
+
+  #[test]
+  fn misses_and_botches_roll_no_damage() {
+    assert_eq!(resolve(attack(15), vec![1, 2, 3]).damage, None);
+    assert_eq!(resolve(attack(0), vec![1, 1, 1]).damage, None);
+  }
+
+  fn sheet(weapon: Weapon) -> CharacterSheet {
+    return CharacterSheet {
+      id: ObjectId::new(),
+      name: "Ayla".into(),
+      level: 1,
+      attributes: AttributeRanks {
+        warfare: Some(3),
+        spirit: Some(1),
+        ..AttributeRanks::default()
+      },
+      attribute_layers: None,
+      training: TrainingRanks::default(),
+      body: BodyStats { hp: 30, constitution: 4, speed: 6 },
+      paths: Default::default(),
+      skills: Vec::new(),
+      flows: None,
+      armor: None,
+      weapons: Some(vec![weapon]),
+      inventory: None,
+      resistances: None,
+      expertise: None,
+      revision: None,
+    };
+  }
+
+  fn request(spec: RollSpec) -> RollRequest {
+    return RollRequest {
+      character_id: ObjectId::new(),
+      session: "Session".into(),
+      spec,
+      mode: Modifier::Advantage,
+      target: Some(12),
+    };
+  }
+
+  #[test]
+  fn resolves_attacks_from_the_sheet() {
+    let weapon = Weapon {
+      title: "Sword".into(),
+      damage_dice: "1d8".parse::<DiceExpression>().unwrap().dice,
+      damage_class: DamageClass::Slashing,
+      ..Weapon::default()
+    };
+    let sheet = sheet(weapon.clone());
+    let mut state = PlayState::new(sheet.id, "Session".into());
+    state.modifiers.push(TemporaryModifier {
+      label: "Blessed".into(),
+      target: Some(CharacterAttribute::Spirit),
+      value: 2,
+    });
+    let spec = RollSpec::Attack { weapon_id: weapon.id, capability: Capability::Spirit };
+    let resolved = request(spec).resolve("Campaign".into(), &sheet, &state, &[]).unwrap();
+    assert_eq!(resolved.roller, "Ayla");
+    assert_eq!(resolved.title, "Sword (Spirit)");
+    assert_eq!(resolved.class, RollClass::Attack);
+    assert_eq!(resolved.bonus, 3);
+    assert_eq!(resolved.damage, Some(weapon.damage()));
+    assert_eq!(resolved.damage_class, Some(DamageClass::Slashing));
+    let spec = RollSpec::Attack { weapon_id: ObjectId::new(), capability: Capability::Warfare };
+    assert!(request(spec).resolve("Campaign".into(), &sheet, &state, &[]).is_err());
+  }
+
+  #[test]
+  fn resolves_checks_from_the_sheet() {
+    let sheet = sheet(Weapon::default());
+    let state = PlayState::new(sheet.id, "Session".into());
+    let roll = Roll {
+      class: RollClass::Check,
+      opening: None,
+      keyword: None,
+      capability: Some(Capability::Warfare),
+      defense: None,
+      alternate_defense: None,
+      modifier: Some(Modifier::Advantage),
+      custom_modifier: None,
+      each: None,
+      custom_target: None,
+      difficulty: None,
+      target: None,
+    };
+    let resolved =
+      request(RollSpec::Check(roll)).resolve("Campaign".into(), &sheet, &state, &[]).unwrap();
+    assert_eq!(resolved.title, "Warfare check");
+    assert_eq!(resolved.bonus, 3);
+    assert_eq!(resolved.mode, Modifier::Advantage);
+    assert_eq!(resolved.target, Some(12));
+  }
+}
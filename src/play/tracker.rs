@@ -0,0 +1,374 @@
+use std::collections::HashMap;
+
+use bson::oid::ObjectId;
+use dioxus::prelude::*;
+
+use super::conditions::ConditionTracker;
+use super::drain::{resolve_drain, DrainOutcome};
+use super::effects::SustainedEffects;
+use super::roller::{CheckRoller, DamageRoller, ExpressionRoller};
+use super::sustain::PoolLimit;
+use super::table::TableRoller;
+use super::{PlayState, PoolUsage, TemporaryModifier};
+use crate::character::prelude::*;
+use crate::common::*;
+use crate::rules::prelude::resistance_to;
+use crate::server::prelude::*;
+use crate::skill::prelude::ResourcePool;
+
+#[component]
+pub fn PlayTracker(sheet: CharacterSheet, session: String) -> Element {
+  let id = sheet.id.to_hex();
+  let state = use_resource(use_reactive!(|(id, session)| async move {
+    get_play_state(id, session).await
+  }));
+  let result = state.read().clone();
+  match result {
+    None => rsx! { div { "Loading session ..." } },
+    Some(Err(error)) => rsx! { div { class: "errored", "Unable to load session: {error}" } },
+    Some(Ok(state)) => rsx! {
+      PlayStateEditor { key: "{state.id}", sheet, state }
+    },
+  }
+}
+
+#[component]
+fn PlayStateEditor(sheet: CharacterSheet, state: PlayState) -> Element {
+  let sheet = sheet_with_catalog(&sheet);
+  let mut draft: Signal<PlayState> = use_signal(|| state.clone());
+  let mut saved: Signal<PlayState> = use_signal(|| state.clone());
+  let mut status: Signal<SaveStatus> = use_signal(|| SaveStatus::Idle);
+  let mut amount: Signal<i32> = use_signal(|| 1);
+  let mut damage_class: Signal<Option<DamageClass>> = use_signal(|| None);
+  let mut upkeep_log: Signal<Vec<(ObjectId, String)>> = use_signal(Vec::new);
+  let mut new_modifier: Signal<TemporaryModifier> = use_signal(|| TemporaryModifier {
+    label: String::new(),
+    target: None,
+    value: 1,
+  });
+  let SkillCache(ref skill_map) = use_context::<SkillCache>();
+  let skills = skill_map.from_object_ids(&sheet.skills);
+  let derived = sheet.derive(&skills);
+
+  let current = draft();
+  let max_hp = derived.body.hp;
+  let hp = (max_hp - current.damage).max(0);
+  let constitution = derived.body.constitution;
+  let flows = derived.flows.clone().unwrap_or_default();
+  let limits: HashMap<ResourcePool, PoolLimit> = flows
+    .iter()
+    .flat_map(|flow| {
+      let limit = move |base: i32| PoolLimit { pool: base, flow: flow.base };
+      flow.pools.iter().map(move |pool| (pool.resource.clone(), limit(pool.base)))
+    })
+    .collect();
+  let upkeep_limits = limits.clone();
+  let resistances = derived.resistances.clone();
+  let weapons = sheet.equipped_weapons();
+  let errors = current.validation_errors();
+  let dirty = current != saved();
+  let saving = status() == SaveStatus::Saving;
+  let save_class = if saving || !dirty || !errors.is_empty() { "disabled" } else { "" };
+  let modifier_draft = new_modifier();
+  let add_class = if modifier_draft.label.trim().is_empty() { "disabled" } else { "" };
+  let session = current.session.clone();
+  let name = sheet.name.clone();
+  let attribute_bonuses: Vec<(CharacterAttribute, i32, i32)> = CharacterAttribute::ordered()
+    .into_iter()
+    .map(|attribute| {
+      let base = derived.attributes.rank(&attribute).unwrap_or_default();
+      let bonus = current.modifier_total(&attribute);
+      (attribute, base, bonus)
+    })
+    .filter(|(_, _, bonus)| *bonus != 0)
+    .collect();
+  let roll_bonuses: Vec<(CharacterAttribute, i32)> = CharacterAttribute::ordered()
+    .into_iter()
+    .filter(|attribute| attribute.is_capacity())
+    .map(|attribute| {
+      let rank = derived.attributes.rank(&attribute).unwrap_or_default();
+      let bonus = rank + current.modifier_total(&attribute);
+      (attribute, bonus)
+    })
+    .collect();
+  rsx! {
+    div {
+      class: "row align-center underhang",
+      div { class: "subheading", "{name} :: {session}" }
+      div {
+        class: "next-button {save_class}",
+        onclick: move |_| {
+          let updated = draft();
+          if saving || updated == saved() || !updated.validation_errors().is_empty() {
+            return;
+          }
+          status.set(SaveStatus::Saving);
+          spawn(async move {
+            match save_play_state(updated).await {
+              Ok(state) => {
+                status.set(SaveStatus::Idle);
+                draft.write().revision = state.revision;
+                saved.set(state);
+              },
+              Err(error) => status.set(SaveStatus::Errored(error.to_string())),
+            }
+          });
+        },
+        match status() {
+          SaveStatus::Saving => "Saving ...",
+          _ => "Save session",
+        }
+      }
+      div {
+        class: "next-button",
+        onclick: move |_| {
+          let mut state = draft.write();
+          state.damage = 0;
+          state.constitution_spent = 0;
+          state.pools.clear();
+          state.modifiers.clear();
+          state.conditions.clear();
+          state.effects.clear();
+          state.round = 0;
+          state.minute = 0;
+        },
+        "Reset"
+      }
+      if dirty {
+        div { class: "italics", "Unsaved changes" }
+      }
+      for error in errors {
+        div { class: "errored", "{error}" }
+      }
+      if let SaveStatus::Errored(error) = status() {
+        div { class: "errored", "{error}" }
+      }
+    }
+    div {
+      class: "card grid dim-keywords",
+      FormRow {
+        title: "Campaign",
+        TextField {
+          value: current.campaign.clone().unwrap_or_default(),
+          onchange: move |value: String| {
+            draft.write().campaign = if value.trim().is_empty() { None } else { Some(value) };
+          },
+        }
+      }
+      div { class: "uv-full subheading", "Body" }
+      FormRow {
+        title: "Health",
+        div { class: "highlight", "{hp} / {max_hp}" }
+        NumberField { value: amount(), onchange: move |value: i32| amount.set(value.max(0)) }
+        EnumSelect {
+          options: DamageClass::ordered(),
+          selected: damage_class(),
+          optional: true,
+          onchange: move |value| damage_class.set(value),
+        }
+        div {
+          class: "next-button",
+          onclick: move |_| {
+            let resisted = match damage_class() {
+              Some(class) => resistance_to(&resistances, &class),
+              None => 0,
+            };
+            let mut state = draft.write();
+            state.damage = (state.damage + (amount() - resisted).max(0)).min(max_hp);
+          },
+          "Damage"
+        }
+        div {
+          class: "next-button",
+          onclick: move |_| {
+            let mut state = draft.write();
+            state.damage = (state.damage - amount()).max(0);
+          },
+          "Heal"
+        }
+      }
+      FormRow {
+        title: "Constitution",
+        div { class: "highlight", "{constitution - current.constitution_spent} / {constitution}" }
+        TrackBoxes {
+          count: constitution,
+          spent: current.constitution_spent,
+          onchange: move |spent: i32| draft.write().constitution_spent = spent,
+        }
+      }
+      if !flows.is_empty() {
+        div { class: "uv-full subheading", "Resources" }
+      }
+      for flow in flows {
+        div { class: "uv-full highlight", "{flow.flow} Flow {flow.base}" }
+        for pool in flow.pools {
+          PoolTracker {
+            usage: current.pool(&pool.resource),
+            base: pool.base,
+            flow: flow.base,
+            onchange: move |usage: PoolUsage| draft.write().set_pool(usage),
+          }
+        }
+      }
+      div { class: "uv-full subheading", "Temporary modifiers" }
+      for (index, modifier) in current.modifiers.iter().cloned().enumerate() {
+        FormRow {
+          title: modifier.label.clone(),
+          div {
+            match &modifier.target {
+              Some(target) => format!("{target} {:+}", modifier.value),
+              None => format!("{:+}", modifier.value),
+            }
+          }
+          div {
+            class: "chip",
+            onclick: move |_| {
+              draft.write().modifiers.remove(index);
+            },
+            "Remove ✕"
+          }
+        }
+      }
+      FormRow {
+        title: "Add modifier",
+        TextField {
+          value: modifier_draft.label.clone(),
+          onchange: move |value| new_modifier.write().label = value,
+        }
+        EnumSelect {
+          options: CharacterAttribute::ordered(),
+          selected: modifier_draft.target.clone(),
+          optional: true,
+          onchange: move |value| new_modifier.write().target = value,
+        }
+        NumberField {
+          value: modifier_draft.value,
+          onchange: move |value| new_modifier.write().value = value,
+        }
+        div {
+          class: "next-button {add_class}",
+          onclick: move |_| {
+            let mut modifier = new_modifier();
+            modifier.label = modifier.label.trim().into();
+            if modifier.label.is_empty() {
+              return;
+            }
+            draft.write().modifiers.push(modifier);
+            new_modifier.write().label = String::new();
+          },
+          "Add"
+        }
+      }
+      for (attribute, base, bonus) in attribute_bonuses {
+        FormRow {
+          title: attribute.to_string(),
+          "{base} {bonus:+} → {base + bonus}"
+        }
+      }
+      ConditionTracker {
+        state: current.clone(),
+        skills: skills.clone(),
+        onchange: move |mut state: PlayState| {
+          let previous = draft();
+          if state.round != previous.round || state.minute != previous.minute {
+            upkeep_log.set(state.pay_upkeep(&upkeep_limits, &mut rand::rng()));
+          }
+          draft.set(state);
+        },
+      }
+      SustainedEffects {
+        state: current.clone(),
+        skills: skills.clone(),
+        limits,
+        log: upkeep_log(),
+        onchange: move |state: PlayState| draft.set(state),
+      }
+      CheckRoller { bonuses: roll_bonuses }
+      DamageRoller { weapons: weapons.clone() }
+      ExpressionRoller {}
+    }
+    if let Some(campaign) = saved().campaign {
+      TableRoller {
+        character_id: sheet.id,
+        session: saved().session,
+        campaign: campaign.trim().to_string(),
+        skills,
+        weapons,
+      }
+    } else {
+      div { class: "italics no-print", "Join a campaign and save the session to roll at the table" }
+    }
+  }
+}
+
+#[component]
+fn PoolTracker(
+  usage: PoolUsage, base: i32, flow: i32, onchange: EventHandler<PoolUsage>,
+) -> Element {
+  let mut amount: Signal<i32> = use_signal(|| 1);
+  let mut outcome: Signal<Option<DrainOutcome>> = use_signal(|| None);
+  let resource = usage.resource.clone();
+  let size = base - usage.drained;
+  let available = size - usage.spent - usage.reserved;
+  let spent_usage = usage.clone();
+  let reserved_usage = usage.clone();
+  let drain_usage = usage.clone();
+  rsx! {
+    FormRow {
+      title: resource.to_string(),
+      div { class: "highlight", "{available} / {size}" }
+      if usage.drained > 0 {
+        div { class: "italics", "{usage.drained} drained" }
+      }
+      div { "Spent" }
+      NumberField {
+        value: usage.spent,
+        onchange: move |value: i32| {
+          onchange.call(PoolUsage { spent: value.clamp(0, base), ..spent_usage.clone() })
+        },
+      }
+      div { "Reserved" }
+      NumberField {
+        value: usage.reserved,
+        onchange: move |value: i32| {
+          onchange.call(PoolUsage { reserved: value.clamp(0, base), ..reserved_usage.clone() })
+        },
+      }
+      if available < 0 {
+        div { class: "errored", "Overspent" }
+      }
+    }
+    FormRow {
+      title: format!("Spend {}", resource.with_drain()),
+      NumberField { value: amount(), onchange: move |value: i32| amount.set(value.max(0)) }
+      div {
+        class: "next-button",
+        onclick: move |_| {
+          let mut usage = drain_usage.clone();
+          let result = resolve_drain(&mut usage, base, flow, amount(), &mut rand::rng());
+          outcome.set(Some(result));
+          onchange.call(usage);
+        },
+        "Spend"
+      }
+      if let Some(result) = outcome() {
+        div { "{result}" }
+      }
+    }
+  }
+}
+
+#[component]
+fn TrackBoxes(count: i32, spent: i32, onchange: EventHandler<i32>) -> Element {
+  rsx! {
+    div {
+      class: "box-row",
+      for index in 0..count.max(0) {
+        div {
+          class: if index < spent { "box spent" } else { "box" },
+          onclick: move |_| onchange.call(if index < spent { index } else { index + 1 }),
+        }
+      }
+    }
+  }
+}
@@ -1,20 +1,24 @@
 use std::collections::{HashMap, HashSet};
 use std::fmt;
 
+use bson::oid::ObjectId;
 use dioxus::prelude::*;
 
 use super::growth::{CharacterGrowth, TrainingGrowthSignals};
 use super::level::LevelSelector;
 use super::paths::CharacterPaths;
 use super::ranks::{CharacterRanks, RankSelections};
+use super::save::CharacterSaver;
 use super::skills::{CharacterSkills, SkillSelections};
 
 use crate::progression::component::ranks::StaticRanks;
 use crate::progression::track::{GrowthTrack, LevelTrack};
 use crate::progression::training::TrainingClass;
 
+use crate::character::prelude::*;
 use crate::modifiers::prelude::*;
 use crate::path::prelude::*;
+use crate::progression::prelude::{character_flows, innate_pools};
 use crate::server::prelude::*;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -280,18 +284,7 @@ pub fn CharacterProgression() -> Element {
   let attribute_ranks = character_modifiers.get(&ModifierClass::AttributeRank);
   let capability_max_ranks = character_modifiers.get(&ModifierClass::CapabilitySpecialization);
   let defense_max_ranks = character_modifiers.get(&ModifierClass::DefenseSpecialization);
-  let mut innate_pools: Vec<(ModifierClass, i32)> = Vec::new();
-  for pool_class in vec![
-    &ModifierClass::AnointmentPool,
-    &ModifierClass::AnimalismPool,
-    &ModifierClass::SanguinePool,
-    &ModifierClass::RagePool,
-  ] {
-    let pool = character_modifiers.get(pool_class);
-    if pool > 0 {
-      innate_pools.push((pool_class.clone(), pool));
-    }
-  }
+  let innate_pools = innate_pools(&character_modifiers);
   let innate_ranks = character_modifiers.get(&ModifierClass::InnatePool);
   let innate_all_ranks = character_modifiers.get(&ModifierClass::InnatePoolAll);
   let static_ranks = StaticRanks {
@@ -300,7 +293,33 @@ pub fn CharacterProgression() -> Element {
     speed: character_modifiers.get(&ModifierClass::WalkingSpeed),
     dash: character_modifiers.get(&ModifierClass::DashSpeed),
   };
-  
+
+  let sheet = CharacterSheet {
+    id: ObjectId::default(),
+    name: String::new(),
+    level,
+    attributes: rank_selections.attribute_ranks(),
+    attribute_layers: Some(rank_selections.attribute_layers()),
+    training: growth_signals.training_ranks(),
+    body: BodyStats {
+      hp: static_ranks.hp,
+      constitution: static_ranks.constituion,
+      speed: static_ranks.speed,
+    },
+    paths: selected_paths()
+      .iter()
+      .filter_map(|path_id| ObjectId::parse_str(path_id).ok())
+      .collect(),
+    skills: skill_selection.learned_skill_ids(),
+    flows: character_flows(&character_modifiers, &rank_selections.pool_ranks(&innate_pools)),
+    armor: None,
+    weapons: None,
+    inventory: None,
+    resistances: None,
+    expertise: rank_selections.expertise_entries(),
+    revision: None,
+  };
+
   rsx! {
     div {
       class: "row",
@@ -308,6 +327,7 @@ pub fn CharacterProgression() -> Element {
       TabSelector { tab: BuilderTab::Paths, current_tab }
       TabSelector { tab: BuilderTab::Skills, current_tab }
       TabSelector { tab: BuilderTab::Attributes, current_tab }
+      CharacterSaver { sheet }
     }
     match current_tab() {
       BuilderTab::Paths => rsx! {
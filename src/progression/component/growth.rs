@@ -3,6 +3,7 @@ use std::i32;
 
 use dioxus::prelude::*;
 
+use crate::character::prelude::TrainingRanks;
 use crate::common::*;
 use crate::progression::component::SelectionState;
 use crate::progression::{track::GrowthTrack, training::TrainingClass};
@@ -30,6 +31,34 @@ impl Default for TrainingGrowthSignals {
   }
 }
 
+impl TrainingGrowthSignals {
+  pub fn signal(&self, class: &TrainingClass) -> Signal<i32> {
+    return match class {
+      TrainingClass::Expert => self.expert,
+      TrainingClass::Adept => self.adept,
+      TrainingClass::Endurance => self.endurance,
+      TrainingClass::Innate => self.innate,
+      TrainingClass::Resonance => self.resonance,
+      TrainingClass::Magic => self.magic,
+    };
+  }
+
+  pub fn training_ranks(&self) -> TrainingRanks {
+    let rank = |signal: Signal<i32>| match signal() {
+      0 => None,
+      value => Some(value),
+    };
+    return TrainingRanks {
+      expert: rank(self.expert),
+      adept: rank(self.adept),
+      endurance: rank(self.endurance),
+      innate: rank(self.innate),
+      resonance: rank(self.resonance),
+      magic: rank(self.magic),
+    };
+  }
+}
+
 #[component]
 pub fn CharacterGrowth(
   growth_signals: TrainingGrowthSignals, growth_ranks_remaining: i32, level: i32,
@@ -0,0 +1,461 @@
+use std::collections::{HashMap, HashSet};
+
+use bson::oid::ObjectId;
+use dioxus::prelude::*;
+
+use super::growth::TrainingGrowthSignals;
+use super::ranks::RankSelections;
+
+use crate::character::prelude::*;
+use crate::common::*;
+use crate::modifiers::prelude::*;
+use crate::path::prelude::*;
+use crate::progression::derive::{
+  character_flows, character_modifiers, stored_pool_ranks, training_classes,
+};
+use crate::progression::prelude::MAX_LEVEL;
+use crate::progression::track::LevelTrack;
+use crate::progression::training::TrainingClass;
+use crate::server::prelude::*;
+use crate::skill::prelude::Skill;
+use crate::Route;
+
+fn combined_training(base: &TrainingRanks, added: &TrainingRanks) -> TrainingRanks {
+  let add = |lhs: Option<i32>, rhs: Option<i32>| match lhs.unwrap_or(0) + rhs.unwrap_or(0) {
+    0 => None,
+    value => Some(value),
+  };
+  return TrainingRanks {
+    expert: add(base.expert, added.expert),
+    adept: add(base.adept, added.adept),
+    endurance: add(base.endurance, added.endurance),
+    innate: add(base.innate, added.innate),
+    resonance: add(base.resonance, added.resonance),
+    magic: add(base.magic, added.magic),
+  };
+}
+
+fn training_enabled(class: &TrainingClass, modifiers: &ModifierSet) -> bool {
+  return match class {
+    TrainingClass::Innate => modifiers.contains_key(&ModifierClass::InnateFlow),
+    TrainingClass::Resonance => modifiers.contains_key(&ModifierClass::ResonanceFlow),
+    TrainingClass::Magic => modifiers.contains_key(&ModifierClass::MagicFlow),
+    _ => true,
+  };
+}
+
+fn gained(before: &ModifierSet, after: &ModifierSet, class: &ModifierClass) -> i32 {
+  return after.get(class) - before.get(class);
+}
+
+#[component]
+pub fn LevelUpWizard(sheet: CharacterSheet) -> Element {
+  let next_level = sheet.level + 1;
+  let new_paths: Signal<HashSet<String>> = use_signal(HashSet::new);
+  let mut new_skills: Signal<HashMap<String, i32>> = use_signal(HashMap::new);
+  let growth_signals = TrainingGrowthSignals::default();
+  let rank_selections = RankSelections::default();
+  let mut status: Signal<SaveStatus> = use_signal(|| SaveStatus::Idle);
+  let nav = navigator();
+  let CharacterSheetCache(sheet_cache) = use_context::<CharacterSheetCache>();
+  let PathCache(ref path_map) = use_context::<PathCache>();
+  let SkillCache(ref skill_map) = use_context::<SkillCache>();
+
+  if sheet.level >= MAX_LEVEL {
+    return rsx! {
+      div { "{sheet.name} is already at the maximum level of {MAX_LEVEL}" }
+    };
+  }
+
+  let level_gains = LevelTrack::gained_at(next_level);
+  let path_slots = level_gains.get(&ModifierClass::InitiatePathMax);
+  let new_path_ids = new_paths();
+  let path_options: Vec<Path> = path_map
+    .into_vec()
+    .into_iter()
+    .filter(|path| !path.inherient.unwrap_or_default() && !sheet.paths.contains(&path.id))
+    .collect();
+
+  let mut character_paths: Vec<Path> = path_map
+    .into_vec()
+    .into_iter()
+    .filter(|path| path.inherient.unwrap_or_default() || sheet.paths.contains(&path.id))
+    .collect();
+  let chosen_paths: Vec<Path> =
+    new_path_ids.iter().filter_map(|path_id| path_map.from_id(path_id)).collect();
+  character_paths.extend(chosen_paths.iter().cloned());
+
+  let current_skills = skill_map.from_object_ids(&sheet.skills);
+  let mut keystones: Vec<ObjectId> = Vec::new();
+  for path in &chosen_paths {
+    for skill in skill_map.from_object_ids(&path.skill_ids.clone().unwrap_or_default()) {
+      let known = sheet.skills.contains(&skill.id) || keystones.contains(&skill.id);
+      if skill.weight() == 0 && !known {
+        keystones.push(skill.id);
+      }
+    }
+  }
+  let mut skill_options: Vec<Skill> = Vec::new();
+  for path in &character_paths {
+    for skill in skill_map.from_object_ids(&path.skill_ids.clone().unwrap_or_default()) {
+      let known = sheet.skills.contains(&skill.id);
+      if skill.weight() == 0 || (known && !skill.is_ranked()) || skill_options.contains(&skill) {
+        continue;
+      }
+      skill_options.push(skill);
+    }
+  }
+  skill_options.sort();
+  let skill_picks = new_skills();
+  let mut added_skill_ids: Vec<ObjectId> = keystones.clone();
+  let mut selected_weight = 0;
+  for skill in &skill_options {
+    let ranks = skill_picks.get(&skill.id.to_string()).cloned().unwrap_or_default();
+    selected_weight += ranks * skill.weight();
+    for _ in 0..ranks {
+      added_skill_ids.push(skill.id);
+    }
+  }
+  let mut unmet_constraints: Vec<String> = Vec::new();
+  for path in &chosen_paths {
+    let (constraints, _) = path.selection_constraints();
+    for constraint in constraints {
+      let filter = &constraint.filter;
+      let weight: i32 = skill_options
+        .iter()
+        .filter(|skill| skill.is_match(filter))
+        .map(|skill| {
+          skill.weight() * skill_picks.get(&skill.id.to_string()).cloned().unwrap_or_default()
+        })
+        .sum();
+      if weight < constraint.required_weight {
+        let skill_weight = filter.skill_filter.weight().max(1);
+        unmet_constraints.push(format!(
+          "{} requires {} {} but only {} were chosen",
+          path.title,
+          constraint.required_weight / skill_weight,
+          filter.skill_filter,
+          weight / skill_weight
+        ));
+      }
+    }
+  }
+  let mut all_skill_ids = sheet.skills.clone();
+  all_skill_ids.extend(added_skill_ids.iter().cloned());
+  let all_skills = skill_map.from_object_ids(&all_skill_ids);
+
+  let added_training = growth_signals.training_ranks();
+  let training = combined_training(&sheet.training, &added_training);
+  let before = character_modifiers(sheet.level, &sheet.training, &current_skills);
+  let after = character_modifiers(next_level, &training, &all_skills);
+  let growth_gained = gained(&before, &after, &ModifierClass::GrowthRanks);
+  let growth_spent: i32 = training_classes(&added_training).iter().map(|(_, rank)| rank).sum();
+  let growth_remaining = growth_gained - growth_spent;
+
+  let mut weight_budget = 2 * gained(&before, &after, &ModifierClass::Feature)
+    + gained(&before, &after, &ModifierClass::MinorFeature);
+  for path in &chosen_paths {
+    let (_, path_budget) = path.selection_constraints();
+    weight_budget += path_budget;
+  }
+  let weight_remaining = weight_budget - selected_weight;
+
+  let flexible_ranks = gained(&before, &after, &ModifierClass::AttributeRank);
+  let capability_ranks = gained(&before, &after, &ModifierClass::CapabilityRank);
+  let defense_ranks = gained(&before, &after, &ModifierClass::DefenseRank);
+  let capability_spent = rank_selections.rank_count_for(true);
+  let defense_spent = rank_selections.rank_count_for(false);
+  let attribute_budget = flexible_ranks + capability_ranks + defense_ranks;
+  let attribute_remaining = attribute_budget - capability_spent - defense_spent;
+  let attributes_valid = attribute_remaining >= 0
+    && capability_spent <= flexible_ranks + capability_ranks
+    && defense_spent <= flexible_ranks + defense_ranks;
+  let capability_specializations =
+    gained(&before, &after, &ModifierClass::CapabilitySpecialization);
+  let defense_specializations = gained(&before, &after, &ModifierClass::DefenseSpecialization);
+  let capability_specializations_remaining =
+    capability_specializations - rank_selections.capacity_max_count();
+  let defense_specializations_remaining =
+    defense_specializations - rank_selections.defense_max_count();
+  let rank_max = after.get(&ModifierClass::RankMax);
+
+  let mut attribute_layers = sheet.attribute_layers();
+  let specialized_before: Vec<CharacterAttribute> = attribute_layers
+    .iter()
+    .filter(|layer| layer.layers.specialization > 0)
+    .map(|layer| layer.attribute.clone())
+    .collect();
+  let mut respecialized = false;
+  for attribute in CharacterAttribute::ordered() {
+    let (rank, max) = rank_selections.get_signals(&attribute);
+    let index = match attribute_layers.iter().position(|layer| layer.attribute == attribute) {
+      Some(index) => index,
+      None => {
+        attribute_layers.push(AttributeLayer { attribute, layers: StatLayers::default() });
+        attribute_layers.len() - 1
+      },
+    };
+    let layers = &mut attribute_layers[index].layers;
+    respecialized |= max() && layers.specialization > 0;
+    layers.ranks += rank();
+    layers.specialization += if max() { 1 } else { 0 };
+  }
+
+  let body = BodyStats {
+    hp: after.get(&ModifierClass::HP),
+    constitution: after.get(&ModifierClass::Constituion),
+    speed: after.get(&ModifierClass::WalkingSpeed),
+  };
+  let flows = character_flows(&after, &stored_pool_ranks(&after, &sheet.flows));
+  let mut paths = sheet.paths.clone();
+  paths.extend(chosen_paths.iter().map(|path| path.id));
+  let mut leveled = CharacterSheet {
+    level: next_level,
+    training: training.clone(),
+    body: body.clone(),
+    paths,
+    skills: all_skill_ids,
+    flows,
+    ..sheet.clone()
+  };
+  leveled.set_attribute_layers(attribute_layers);
+
+  let path_count = i32::try_from(new_path_ids.len()).unwrap_or_default();
+  let valid = path_count <= path_slots
+    && growth_remaining >= 0
+    && weight_remaining >= 0
+    && attributes_valid
+    && !respecialized
+    && unmet_constraints.is_empty()
+    && capability_specializations_remaining >= 0
+    && defense_specializations_remaining >= 0;
+  let saving = status() == SaveStatus::Saving;
+  let save_class = if saving || !valid { "disabled" } else { "" };
+  let name = sheet.name.clone();
+  let level = sheet.level;
+  rsx! {
+    div {
+      class: "column underhang",
+      div { class: "subheading", "Level up {name} from {level} to {next_level}" }
+      div { "Level {next_level} grants: {level_gains}" }
+      div { "HP {sheet.body.hp} → {body.hp}" }
+      div { "Constitution {sheet.body.constitution} → {body.constitution}" }
+    }
+    if path_slots > 0 {
+      div {
+        class: "column underhang",
+        div { class: "subheading", "New paths: {path_count} / {path_slots}" }
+        for path in path_options {
+          LevelUpPathOption { path, new_paths, path_slots }
+        }
+      }
+    }
+    div {
+      class: "column underhang",
+      div { class: "subheading", "Growth ranks remaining: {growth_remaining} / {growth_gained}" }
+      div {
+        class: "grid dim-quad",
+        for (class, rank) in training_classes(&sheet.training) {
+          LevelUpGrowthRow {
+            class,
+            current: rank,
+            rank: growth_signals.signal(&class),
+            level: next_level,
+            remaining: growth_remaining,
+            enabled: training_enabled(&class, &after),
+          }
+        }
+      }
+    }
+    div {
+      class: "column underhang",
+      div { class: "subheading", "Attribute ranks: {attribute_remaining} / {attribute_budget}" }
+      if capability_specializations > 0 {
+        div {
+          "Capabilities to specialize: {capability_specializations_remaining} / "
+          "{capability_specializations}"
+        }
+      }
+      if defense_specializations > 0 {
+        div {
+          "Defenses to specialize: {defense_specializations_remaining} / {defense_specializations}"
+        }
+      }
+      div {
+        class: "grid dim-quad",
+        for attribute in CharacterAttribute::ordered() {
+          LevelUpAttributeRow {
+            current: sheet.attributes.rank(&attribute).unwrap_or_default(),
+            rank_max,
+            remaining: attribute_remaining,
+            can_specialize: if attribute.is_capacity() {
+              capability_specializations_remaining > 0
+            } else {
+              defense_specializations_remaining > 0
+            },
+            locked: specialized_before.contains(&attribute),
+            rank_selections: rank_selections.clone(),
+            attribute,
+          }
+        }
+      }
+    }
+    div {
+      class: "column underhang",
+      div { class: "subheading", "Feature weight remaining: {weight_remaining} / {weight_budget}" }
+      for unmet in unmet_constraints {
+        div { class: "errored", "{unmet}" }
+      }
+      for skill in skill_options {
+        div {
+          class: "row align-center",
+          LevelUpSkillOption {
+            ranks: skill_picks.get(&skill.id.to_string()).cloned().unwrap_or_default(),
+            max_ranks: match skill.is_ranked() {
+              true => skill_picks.get(&skill.id.to_string()).cloned().unwrap_or_default()
+                + weight_remaining / skill.weight().max(1),
+              false => 1,
+            },
+            onchange: move |(id, ranks): (String, i32)| {
+              new_skills.write().insert(id, ranks);
+            },
+            skill,
+          }
+        }
+      }
+    }
+    div {
+      class: "row align-center",
+      div {
+        class: "next-button {save_class}",
+        onclick: move |_| {
+          if saving || !valid {
+            return;
+          }
+          let updated = leveled.clone();
+          let cache = sheet_cache.clone();
+          status.set(SaveStatus::Saving);
+          spawn(async move {
+            match update_character_sheet(updated).await {
+              Ok(saved) => {
+                status.set(SaveStatus::Idle);
+                cache.restart();
+                nav.push(Route::SingleCharacterSheetPage { id: saved.id.to_hex() });
+              },
+              Err(error) => status.set(SaveStatus::Errored(error.to_string())),
+            }
+          });
+        },
+        match status() {
+          SaveStatus::Saving => "Saving ...",
+          _ => "Save level {next_level}",
+        }
+      }
+      if let SaveStatus::Errored(error) = status() {
+        div { class: "errored", "Unable to save character: {error}" }
+      }
+    }
+  }
+}
+
+#[component]
+fn LevelUpPathOption(path: Path, new_paths: Signal<HashSet<String>>, path_slots: i32) -> Element {
+  let id = path.id.to_string();
+  let selected = new_paths().contains(&id);
+  let full = i32::try_from(new_paths().len()).unwrap_or_default() >= path_slots;
+  let conditional_class = match (selected, full) {
+    (true, _) => "",
+    (false, true) => "disabled",
+    (false, false) => "",
+  };
+  let title = path.title;
+  rsx! {
+    div {
+      class: "row align-center {conditional_class}",
+      input {
+        r#type: "checkbox",
+        checked: selected,
+        disabled: full && !selected,
+        onchange: move |_| {
+          let mut paths = new_paths.write();
+          if !paths.remove(&id) {
+            paths.insert(id.clone());
+          }
+        },
+      }
+      div { "{title}" }
+    }
+  }
+}
+
+#[component]
+fn LevelUpGrowthRow(
+  class: TrainingClass, current: i32, rank: Signal<i32>, level: i32, remaining: i32,
+  enabled: bool,
+) -> Element {
+  let conditional_class = if enabled { "" } else { "disabled" };
+  let max_rank = match enabled {
+    true => (level - current).min(remaining + rank()).max(0),
+    false => 0,
+  };
+  rsx! {
+    div { class: "{conditional_class}", "{class}" }
+    div { "{current}" }
+    InputSignal { rank, max_rank }
+    div { "→ {current + rank()}" }
+  }
+}
+
+#[component]
+fn LevelUpAttributeRow(
+  attribute: CharacterAttribute, current: i32, rank_max: i32, remaining: i32,
+  can_specialize: bool, locked: bool, rank_selections: RankSelections,
+) -> Element {
+  let (rank, mut max) = rank_selections.get_signals(&attribute);
+  let specialized = max();
+  let cap = rank_max + if specialized || current > rank_max { 1 } else { 0 };
+  let max_rank = (cap - current - if specialized { 1 } else { 0 }).min(remaining + rank()).max(0);
+  let value = current + rank() + if specialized { 1 } else { 0 };
+  rsx! {
+    div { "{attribute}" }
+    div { "{current}" }
+    InputSignal { rank, max_rank }
+    div {
+      class: "row align-center",
+      "→ {value}"
+      // An attribute can only be specialized once, so earlier levels lock the toggle
+      if locked {
+        input { r#type: "checkbox", checked: true, disabled: true }
+        "Specialized"
+      } else if can_specialize || specialized {
+        input {
+          r#type: "checkbox",
+          checked: specialized,
+          onchange: move |_| max.set(!specialized),
+        }
+        "Specialize"
+      }
+    }
+  }
+}
+
+#[component]
+fn LevelUpSkillOption(
+  skill: Skill, ranks: i32, max_ranks: i32, onchange: EventHandler<(String, i32)>,
+) -> Element {
+  let id = skill.id.to_string();
+  let title = skill.title.clone();
+  let requirements = skill.training_requirements();
+  rsx! {
+    input {
+      class: "input", r#type: "number",
+      value: ranks, min: 0, max: max_ranks,
+      oninput: move |event| {
+        let value = event.value().parse::<i32>().unwrap_or_default().min(max_ranks).max(0);
+        onchange.call((id.clone(), value));
+      },
+    }
+    Link { to: Route::SingleSkillPage { id: skill.id.to_string() }, "{title}" }
+    span { class: "small-text indent-small", "{requirements}" }
+  }
+}
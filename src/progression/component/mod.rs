@@ -1,11 +1,14 @@
 mod builder;
 mod growth;
 mod level;
+mod level_up;
 mod paths;
 pub mod ranks;
+mod save;
 mod skills;
 
 pub use builder::CharacterProgression;
+pub use level_up::LevelUpWizard;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum SelectionState {
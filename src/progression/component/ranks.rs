@@ -1,9 +1,12 @@
+use std::collections::HashMap;
+
 use dioxus::prelude::*;
 
 use crate::character::prelude::*;
 use crate::modifiers::ModifierClass;
 use crate::progression::prelude::*;
 use crate::rules::components::Modifier;
+use crate::rules::prelude::AttributeRanks;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct RankSignal {
@@ -86,6 +89,68 @@ impl RankSelections {
     return (rank_signal.rank, rank_signal.max);
   }
 
+  pub fn pool_signal(&self, class: &ModifierClass) -> Option<Signal<i32>> {
+    return match class {
+      ModifierClass::AnointmentPool => Some(self.anointment_pool),
+      ModifierClass::AnimalismPool => Some(self.animalism_pool),
+      ModifierClass::SanguinePool => Some(self.sanguine_pool),
+      ModifierClass::RagePool => Some(self.rage_pool),
+      _ => None,
+    };
+  }
+
+  pub fn pool_ranks(&self, innate_pools: &[(ModifierClass, i32)]) -> HashMap<ModifierClass, i32> {
+    return innate_pools
+      .iter()
+      .filter_map(|(class, _)| Some((class.clone(), self.pool_signal(class)?())))
+      .collect();
+  }
+
+  pub fn attribute_ranks(&self) -> AttributeRanks {
+    let value = |signal: &RankSignal| Some((signal.rank)() + signal.max_value());
+    return AttributeRanks {
+      physique: value(&self.physique),
+      warfare: value(&self.warfare),
+      spirit: value(&self.spirit),
+      manipulation: value(&self.manipulation),
+      tenacity: value(&self.tenacity),
+      fortitude: value(&self.fortitude),
+      resolve: value(&self.resolve),
+      insight: value(&self.insight),
+      dodge: None,
+    };
+  }
+
+  pub fn attribute_layers(&self) -> Vec<AttributeLayer> {
+    return CharacterAttribute::ordered()
+      .into_iter()
+      .map(|attribute| {
+        let (rank, max) = self.get_signals(&attribute);
+        let specialization = if max() { 1 } else { 0 };
+        let layers = StatLayers { ranks: rank(), specialization, enchantment: 0 };
+        AttributeLayer { attribute, layers }
+      })
+      .collect();
+  }
+
+  pub fn expertise_entries(&self) -> Option<Vec<ExpertiseEntry>> {
+    let expertise = (self.expertise)();
+    if expertise.is_empty() {
+      return None;
+    }
+    let entries = expertise
+      .into_iter()
+      .map(|(title, signal)| ExpertiseEntry {
+        title: Some(title),
+        ranks: Some((signal.rank)()),
+        empty: None,
+        specialization: Some(signal.max_value()),
+        enchantment: None,
+      })
+      .collect();
+    return Some(entries);
+  }
+
   pub fn rank_count(&self) -> i32 {
     return (self.physique.rank)()
       + (self.warfare.rank)()
@@ -97,6 +162,14 @@ impl RankSelections {
       + (self.insight.rank)();
   }
 
+  pub fn rank_count_for(&self, capability: bool) -> i32 {
+    return CharacterAttribute::ordered()
+      .iter()
+      .filter(|attribute| attribute.is_capacity() == capability)
+      .map(|attribute| (self.get_signals(attribute).0)())
+      .sum();
+  }
+
   pub fn capacity_max_count(&self) -> i32 {
     return self.physique.max_value()
       + self.warfare.max_value()
@@ -159,13 +232,14 @@ pub fn CharacterRanks(
     });
   }
   for (class, pool) in innate_pools {
-    let (title, ranks_base) = match &class {
-      ModifierClass::AnointmentPool => ("Anointment", Some(rank_selections.anointment_pool)),
-      ModifierClass::AnimalismPool => ("Animalism", Some(rank_selections.animalism_pool)),
-      ModifierClass::SanguinePool => ("Sanguine", Some(rank_selections.sanguine_pool)),
-      ModifierClass::RagePool => ("Rage", Some(rank_selections.rage_pool)),
-      _ => ("other", None),
+    let title = match &class {
+      ModifierClass::AnointmentPool => "Anointment",
+      ModifierClass::AnimalismPool => "Animalism",
+      ModifierClass::SanguinePool => "Sanguine",
+      ModifierClass::RagePool => "Rage",
+      _ => "other",
     };
+    let ranks_base = rank_selections.pool_signal(&class);
     let ranks_optional = if innate_selector { ranks_base } else { None };
     let flat_bonus = pool + innate_all_ranks + if innate_selector { 0 } else { innate_ranks };
     resource_selectors.push( rsx! {
@@ -0,0 +1,53 @@
+use dioxus::prelude::*;
+
+use crate::character::prelude::CharacterSheet;
+use crate::common::SaveStatus;
+use crate::server::prelude::insert_character_sheet;
+use crate::Route;
+
+#[component]
+pub fn CharacterSaver(sheet: CharacterSheet) -> Element {
+  let mut name: Signal<String> = use_signal(String::new);
+  let mut status: Signal<SaveStatus> = use_signal(|| SaveStatus::Idle);
+  let nav = navigator();
+  let disabled = status() == SaveStatus::Saving || name().trim().is_empty();
+  let conditional_class = if disabled { "disabled" } else { "" };
+  rsx! {
+    div {
+      class: "row align-center",
+      input {
+        class: "input-text", r#type: "text",
+        placeholder: "Character Name",
+        value: name(),
+        oninput: move |event| { name.set(event.value()); },
+      }
+      div {
+        class: "next-button {conditional_class}",
+        onclick: move |_| {
+          if disabled {
+            return;
+          }
+          let mut new_sheet = sheet.clone();
+          new_sheet.name = name().trim().into();
+          status.set(SaveStatus::Saving);
+          spawn(async move {
+            match insert_character_sheet(new_sheet).await {
+              Ok(id) => {
+                status.set(SaveStatus::Idle);
+                nav.push(Route::SingleCharacterSheetPage { id });
+              },
+              Err(error) => status.set(SaveStatus::Errored(error.to_string())),
+            }
+          });
+        },
+        match status() {
+          SaveStatus::Saving => "Saving ...",
+          _ => "Save character",
+        }
+      }
+      if let SaveStatus::Errored(error) = status() {
+        div { class: "errored", "Unable to save character: {error}" }
+      }
+    }
+  }
+}
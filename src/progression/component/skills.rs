@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use bson::oid::ObjectId;
 use dioxus::prelude::*;
 
 use crate::common::*;
@@ -47,6 +48,20 @@ impl SkillSelections {
     skills.sort();
     return skills;
   }
+
+  pub fn learned_skill_ids(&self) -> Vec<ObjectId> {
+    let rank_map = (self.rank_signal)();
+    let mut keystones: Vec<Skill> = self.keystones.values().cloned().collect();
+    keystones.sort();
+    let mut skill_ids: Vec<ObjectId> = keystones.iter().map(|skill| skill.id).collect();
+    for skill in self.to_vec() {
+      let ranks = *rank_map.get(&skill.id.to_string()).unwrap_or(&0);
+      for _ in 0..ranks {
+        skill_ids.push(skill.id);
+      }
+    }
+    return skill_ids;
+  }
 }
 
 #[component]
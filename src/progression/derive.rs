@@ -0,0 +1,340 @@
+use std::cmp::max;
+use std::collections::HashMap;
+
+use crate::character::prelude::*;
+use crate::equipment::armor::Armor;
+use crate::equipment::shield::Shield;
+use crate::modifiers::prelude::*;
+use crate::rules::prelude::AttributeRanks;
+use crate::skill::prelude::{ResourcePool, Skill};
+
+use super::track::{GrowthTrack, LevelTrack};
+use super::training::TrainingClass;
+
+const INNATE_POOLS: [ModifierClass; 4] = [
+  ModifierClass::AnointmentPool,
+  ModifierClass::AnimalismPool,
+  ModifierClass::SanguinePool,
+  ModifierClass::RagePool,
+];
+
+const RESONANCE_POOLS: [ModifierClass; 4] = [
+  ModifierClass::MasteryPool,
+  ModifierClass::ChannelPool,
+  ModifierClass::KiPool,
+  ModifierClass::VirtuosoPool,
+];
+
+const MANA_POOLS: [ModifierClass; 3] = [
+  ModifierClass::ManaPoolMinor,
+  ModifierClass::ManaPoolModerate,
+  ModifierClass::ManaPoolMajor,
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatInputs<'a> {
+  pub level: i32,
+  pub training: &'a TrainingRanks,
+  pub skills: &'a [Skill],
+  pub attributes: &'a AttributeRanks,
+  pub pool_ranks: HashMap<ModifierClass, i32>,
+  pub armor: &'a Option<Armor>,
+  pub shield: &'a Option<Shield>,
+  pub resistances: &'a Option<Resistances>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DerivedStats {
+  pub modifiers: ModifierSet,
+  pub body: BodyStats,
+  pub dash: i32,
+  pub attributes: AttributeRanks,
+  pub resistances: Resistances,
+  pub flows: Option<Vec<FlowStat>>,
+  pub bulk_fits: bool,
+}
+
+pub fn training_classes(training: &TrainingRanks) -> Vec<(TrainingClass, i32)> {
+  return vec![
+    (TrainingClass::Expert, training.expert.unwrap_or_default()),
+    (TrainingClass::Adept, training.adept.unwrap_or_default()),
+    (TrainingClass::Endurance, training.endurance.unwrap_or_default()),
+    (TrainingClass::Innate, training.innate.unwrap_or_default()),
+    (TrainingClass::Resonance, training.resonance.unwrap_or_default()),
+    (TrainingClass::Magic, training.magic.unwrap_or_default()),
+  ];
+}
+
+pub fn character_modifiers(level: i32, training: &TrainingRanks, skills: &[Skill]) -> ModifierSet {
+  let mut modifiers = LevelTrack::as_of(level);
+  for (class, rank) in training_classes(training) {
+    modifiers.append(&GrowthTrack::class_at(&class, rank));
+  }
+  for skill in skills {
+    if let Some(skill_modifiers) = &skill.modifiers {
+      modifiers.append(skill_modifiers);
+    }
+  }
+  return modifiers;
+}
+
+pub fn innate_pools(modifiers: &ModifierSet) -> Vec<(ModifierClass, i32)> {
+  return INNATE_POOLS
+    .iter()
+    .map(|class| (class.clone(), modifiers.get(class)))
+    .filter(|(_, pool)| *pool > 0)
+    .collect();
+}
+
+pub fn character_flows(
+  modifiers: &ModifierSet, pool_ranks: &HashMap<ModifierClass, i32>,
+) -> Option<Vec<FlowStat>> {
+  let mut flows: Vec<FlowStat> = Vec::new();
+
+  let innate_flow = modifiers.get(&ModifierClass::InnateFlow);
+  if innate_flow > 0 {
+    let innate_pools = innate_pools(modifiers);
+    let innate_ranks = modifiers.get(&ModifierClass::InnatePool);
+    let innate_all_ranks = modifiers.get(&ModifierClass::InnatePoolAll);
+    let innate_selector = innate_pools.len() > 1 && innate_ranks > 0;
+    let pools = innate_pools
+      .iter()
+      .filter_map(|(class, pool)| {
+        let selected = match innate_selector {
+          true => pool_ranks.get(class).cloned().unwrap_or_default(),
+          false => innate_ranks,
+        };
+        Some(ResourceStat {
+          resource: resource_pool_for(class)?,
+          base: pool + innate_all_ranks + selected,
+        })
+      })
+      .collect();
+    flows.push(FlowStat { flow: Flow::Innate, base: innate_flow, pools });
+  }
+
+  if modifiers.contains_key(&ModifierClass::ResonanceFlow) {
+    let resonance_classes: Vec<&ModifierClass> =
+      RESONANCE_POOLS.iter().filter(|class| modifiers.get(class) > 0).collect();
+    let single_pool_ranks = match resonance_classes.len() {
+      1 => modifiers.get(&ModifierClass::ResonancePool),
+      _ => 0,
+    };
+    let resonance_all_ranks = modifiers.get(&ModifierClass::ResonancePoolAll);
+    let pools = resonance_classes
+      .into_iter()
+      .filter_map(|class| {
+        Some(ResourceStat {
+          resource: resource_pool_for(class)?,
+          base: modifiers.get(class) + resonance_all_ranks + single_pool_ranks,
+        })
+      })
+      .collect();
+    flows.push(FlowStat {
+      flow: Flow::Resonance,
+      base: modifiers.get(&ModifierClass::ResonanceFlow),
+      pools,
+    });
+  }
+
+  if modifiers.contains_key(&ModifierClass::MagicFlow) {
+    let pools = MANA_POOLS
+      .iter()
+      .filter(|class| modifiers.get(class) > 0)
+      .filter_map(|class| {
+        Some(ResourceStat {
+          resource: resource_pool_for(class)?,
+          base: modifiers.get(class),
+        })
+      })
+      .collect();
+    flows.push(FlowStat {
+      flow: Flow::Magic,
+      base: modifiers.get(&ModifierClass::MagicFlow),
+      pools,
+    });
+  }
+
+  return match flows.len() {
+    0 => None,
+    _ => Some(flows),
+  };
+}
+
+pub fn resource_pool_for(class: &ModifierClass) -> Option<ResourcePool> {
+  return match class {
+    ModifierClass::AnointmentPool => Some(ResourcePool::Anointment),
+    ModifierClass::AnimalismPool => Some(ResourcePool::Animalism),
+    ModifierClass::SanguinePool => Some(ResourcePool::Sanguine),
+    ModifierClass::RagePool => Some(ResourcePool::Rage),
+    ModifierClass::MasteryPool => Some(ResourcePool::Mastery),
+    ModifierClass::ChannelPool => Some(ResourcePool::Channel),
+    ModifierClass::KiPool => Some(ResourcePool::Ki),
+    ModifierClass::VirtuosoPool => Some(ResourcePool::Virtuoso),
+    ModifierClass::ManaPoolMinor => Some(ResourcePool::MinorMana),
+    ModifierClass::ManaPoolModerate => Some(ResourcePool::ModerateMana),
+    ModifierClass::ManaPoolMajor => Some(ResourcePool::MajorMana),
+    _ => None,
+  };
+}
+
+pub fn stored_pool_ranks(
+  modifiers: &ModifierSet, flows: &Option<Vec<FlowStat>>,
+) -> HashMap<ModifierClass, i32> {
+  let mut pool_ranks = HashMap::new();
+  let innate_ranks = modifiers.get(&ModifierClass::InnatePool);
+  let innate_all_ranks = modifiers.get(&ModifierClass::InnatePoolAll);
+  let stored_pools: Vec<&ResourceStat> = flows
+    .iter()
+    .flatten()
+    .filter(|flow| flow.flow == Flow::Innate)
+    .flat_map(|flow| flow.pools.iter())
+    .collect();
+  for (class, pool) in innate_pools(modifiers) {
+    let Some(stored) = stored_pools
+      .iter()
+      .find(|stored| Some(&stored.resource) == resource_pool_for(&class).as_ref())
+    else {
+      continue;
+    };
+    let selected = (stored.base - pool - innate_all_ranks).clamp(0, innate_ranks.max(0));
+    pool_ranks.insert(class, selected);
+  }
+  return pool_ranks;
+}
+
+pub fn derive_stats(inputs: StatInputs) -> DerivedStats {
+  let modifiers = character_modifiers(inputs.level, inputs.training, inputs.skills);
+  let flows = character_flows(&modifiers, &inputs.pool_ranks);
+  let body = BodyStats {
+    hp: modifiers.get(&ModifierClass::HP),
+    constitution: modifiers.get(&ModifierClass::Constituion),
+    speed: modifiers.get(&ModifierClass::WalkingSpeed),
+  };
+  let dash = modifiers.get(&ModifierClass::DashSpeed);
+  let mut attributes = inputs.attributes.clone();
+  let mut resistances = inputs.resistances.clone().unwrap_or_default();
+  let mut bulk = 0;
+  let mut speed_penalty = 0;
+  if let Some(armor) = inputs.armor {
+    resistances.update_physical_resistance(armor.physical_resistance);
+    bulk += armor.tenacity_requirement;
+    speed_penalty = armor.speed_penalty.unwrap_or_default();
+  }
+  if let Some(shield) = inputs.shield {
+    for category in &shield.categories {
+      resistances.add_block(category, shield.block);
+    }
+    bulk += shield.tenacity_requirement;
+  }
+  let (bulk_fits, _) = attributes.update_dodge_with_bulk(bulk, speed_penalty);
+  let armored = inputs.armor.is_some();
+  let armored_body = BodyStats { speed: max(body.speed - speed_penalty, 1), ..body };
+  return DerivedStats {
+    modifiers,
+    body: if armored { armored_body } else { body },
+    dash: if armored { max(dash - speed_penalty, 1) } else { dash },
+    attributes,
+    resistances,
+    flows,
+    bulk_fits,
+  };
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn derive(tenacity: i32, armor: &Option<Armor>, shield: &Option<Shield>) -> DerivedStats {
+    let attributes = AttributeRanks { tenacity: Some(tenacity), ..AttributeRanks::default() };
+    return derive_stats(StatInputs {
+      level: 1,
+      training: &TrainingRanks::default(),
+      skills: &[],
+      attributes: &attributes,
+      pool_ranks: HashMap::new(),
+      armor,
+      shield,
+      resistances: &None,
+    });
+  }
+
+  fn armor(tenacity_requirement: i32, speed_penalty: i32) -> Option<Armor> {
+    return Some(Armor {
+      title: "Brigandine".into(),
+      physical_resistance: 2,
+      tenacity_requirement,
+      speed_penalty: Some(speed_penalty),
+      ..Armor::default()
+    });
+  }
+
+  fn base_movement() -> (i32, i32) {
+    let modifiers = character_modifiers(1, &TrainingRanks::default(), &[]);
+    return (
+      modifiers.get(&ModifierClass::WalkingSpeed),
+      modifiers.get(&ModifierClass::DashSpeed),
+    );
+  }
+
+  #[test]
+  fn unarmored_keeps_full_dodge_and_movement() {
+    let (speed, dash) = base_movement();
+    let stats = derive(3, &None, &None);
+    assert_eq!(stats.attributes.dodge, Some(3));
+    assert_eq!(stats.body.speed, speed);
+    assert_eq!(stats.dash, dash);
+    assert!(stats.bulk_fits);
+  }
+
+  #[test]
+  fn armor_reduces_dodge_and_movement() {
+    let (speed, dash) = base_movement();
+    let stats = derive(4, &armor(1, 2), &None);
+    assert_eq!(stats.attributes.dodge, Some(1));
+    assert_eq!(stats.body.speed, max(speed - 2, 1));
+    assert_eq!(stats.dash, max(dash - 2, 1));
+    assert!(stats.bulk_fits);
+  }
+
+  #[test]
+  fn armor_never_stops_movement() {
+    let stats = derive(4, &armor(0, 100), &None);
+    assert_eq!(stats.attributes.dodge, Some(0));
+    assert_eq!(stats.body.speed, 1);
+    assert_eq!(stats.dash, 1);
+  }
+
+  #[test]
+  fn too_bulky_equipment_does_not_fit() {
+    let shield = Some(Shield { tenacity_requirement: 2, ..Shield::default() });
+    let stats = derive(2, &armor(1, 0), &shield);
+    assert_eq!(stats.attributes.dodge, Some(0));
+    assert!(!stats.bulk_fits);
+  }
+
+  #[test]
+  fn stored_pool_ranks_are_clamped() {
+    let mut modifiers = ModifierSet::default();
+    modifiers.add_bonus(&ModifierClass::InnatePool, 2);
+    modifiers.add_bonus(&ModifierClass::InnatePoolAll, 1);
+    modifiers.add_bonus(&ModifierClass::AnointmentPool, 3);
+    modifiers.add_bonus(&ModifierClass::AnimalismPool, 3);
+    modifiers.add_bonus(&ModifierClass::RagePool, 2);
+    let stored = |resource: ResourcePool, base: i32| ResourceStat { resource, base };
+    let flows = Some(vec![FlowStat {
+      flow: Flow::Innate,
+      base: 1,
+      pools: vec![
+        stored(ResourcePool::Anointment, 9),
+        stored(ResourcePool::Animalism, 5),
+        stored(ResourcePool::Rage, 2),
+      ],
+    }]);
+    let pool_ranks = stored_pool_ranks(&modifiers, &flows);
+    assert_eq!(pool_ranks.get(&ModifierClass::AnointmentPool), Some(&2));
+    assert_eq!(pool_ranks.get(&ModifierClass::AnimalismPool), Some(&1));
+    assert_eq!(pool_ranks.get(&ModifierClass::RagePool), Some(&0));
+    assert_eq!(pool_ranks.get(&ModifierClass::SanguinePool), None);
+  }
+}
@@ -0,0 +1,415 @@
+use std::collections::{HashMap, HashSet};
+
+use bson::oid::ObjectId;
+
+use crate::character::prelude::*;
+use crate::modifiers::prelude::*;
+use crate::path::prelude::*;
+use crate::skill::prelude::Skill;
+
+use super::derive::{character_modifiers, training_classes};
+use super::track::LevelTrack;
+use super::training::TrainingClass;
+
+struct AttributeSpend {
+  spent: i32,
+  specialized: i32,
+}
+
+fn attribute_spend(layers: &[&AttributeLayer]) -> AttributeSpend {
+  return AttributeSpend {
+    spent: layers.iter().map(|layer| layer.layers.ranks).sum(),
+    specialized: layers.iter().filter(|layer| layer.layers.specialization > 0).count() as i32,
+  };
+}
+
+fn modeled_layers(sheet: &CharacterSheet, rank_max: i32) -> Vec<AttributeLayer> {
+  let mut layers = sheet.attribute_layers();
+  if sheet.attribute_layers.is_none() {
+    for layer in layers.iter_mut().filter(|layer| layer.layers.ranks > rank_max) {
+      layer.layers.ranks -= 1;
+      layer.layers.specialization = 1;
+    }
+  }
+  return layers;
+}
+
+fn attribute_violations(
+  violations: &mut Vec<String>, sheet: &CharacterSheet, modifiers: &ModifierSet,
+) {
+  let rank_max = modifiers.get(&ModifierClass::RankMax);
+  let attribute_ranks = modifiers.get(&ModifierClass::AttributeRank);
+  let capability_ranks = modifiers.get(&ModifierClass::CapabilityRank);
+  let defense_ranks = modifiers.get(&ModifierClass::DefenseRank);
+  let capability_specializations = modifiers.get(&ModifierClass::CapabilitySpecialization);
+  let defense_specializations = modifiers.get(&ModifierClass::DefenseSpecialization);
+  let layers = modeled_layers(sheet, rank_max);
+
+  for layer in &layers {
+    let (attribute, ranks) = (&layer.attribute, layer.layers.ranks);
+    if ranks > rank_max {
+      violations.push(format!(
+        "{attribute} has {ranks} ranks before specialization but the maximum is {rank_max}"
+      ));
+    }
+    if layer.layers.specialization > 1 {
+      violations.push(format!("{attribute} can only be specialized once"));
+    }
+  }
+
+  let (capabilities, defenses): (Vec<&AttributeLayer>, Vec<&AttributeLayer>) =
+    layers.iter().partition(|layer| layer.attribute.is_capacity());
+  let capability = attribute_spend(&capabilities);
+  let defense = attribute_spend(&defenses);
+  if capability.specialized > capability_specializations {
+    violations.push(format!(
+      "{} capabilities are specialized but only {capability_specializations} can be",
+      capability.specialized
+    ));
+  }
+  if defense.specialized > defense_specializations {
+    violations.push(format!(
+      "{} defenses are specialized but only {defense_specializations} can be",
+      defense.specialized
+    ));
+  }
+  let available = attribute_ranks + capability_ranks + defense_ranks;
+  if capability.spent > attribute_ranks + capability_ranks {
+    violations.push(format!(
+      "{} capability ranks spent but only {} are available",
+      capability.spent,
+      attribute_ranks + capability_ranks
+    ));
+  }
+  if defense.spent > attribute_ranks + defense_ranks {
+    violations.push(format!(
+      "{} defense ranks spent but only {} are available",
+      defense.spent,
+      attribute_ranks + defense_ranks
+    ));
+  }
+  if capability.spent + defense.spent > available {
+    violations.push(format!(
+      "{} attribute ranks spent but only {available} are available",
+      capability.spent + defense.spent
+    ));
+  }
+}
+
+fn growth_violations(
+  violations: &mut Vec<String>, sheet: &CharacterSheet, untrained_modifiers: &ModifierSet,
+) {
+  let growth_ranks = untrained_modifiers.get(&ModifierClass::GrowthRanks);
+  let mut spent = 0;
+  for (class, rank) in training_classes(&sheet.training) {
+    spent += rank;
+    if rank < 0 {
+      violations.push(format!("{class} training cannot be negative"));
+    }
+    if rank > sheet.level {
+      violations.push(format!(
+        "{class} training is rank {rank} but cannot exceed the character level {}",
+        sheet.level
+      ));
+    }
+    let flow = match class {
+      TrainingClass::Innate => Some(ModifierClass::InnateFlow),
+      TrainingClass::Resonance => Some(ModifierClass::ResonanceFlow),
+      TrainingClass::Magic => Some(ModifierClass::MagicFlow),
+      _ => None,
+    };
+    if let Some(flow) = flow {
+      if rank > 0 && !untrained_modifiers.contains_key(&flow) {
+        violations.push(format!("{class} training requires a path granting {class} flow"));
+      }
+    }
+  }
+  if spent > growth_ranks {
+    violations.push(format!(
+      "{spent} growth ranks spent but level {} only grants {growth_ranks}",
+      sheet.level
+    ));
+  }
+}
+
+pub fn legality_violations(
+  sheet: &CharacterSheet, paths: &HashMap<String, Path>, skills: &HashMap<String, Skill>,
+) -> Vec<String> {
+  let mut violations: Vec<String> = Vec::new();
+  let level_modifiers = LevelTrack::as_of(sheet.level);
+  let path_min = level_modifiers.get(&ModifierClass::InitiatePathMin);
+  let path_max = level_modifiers.get(&ModifierClass::InitiatePathMax);
+
+  let mut selected_paths: Vec<Path> = Vec::new();
+  for path_id in &sheet.paths {
+    match paths.get(&path_id.to_string()) {
+      Some(path) if path.inherient.unwrap_or_default() => {
+        violations.push(format!("{} is inherient and cannot be selected", path.title));
+      },
+      Some(path) => selected_paths.push(path.clone()),
+      None => violations.push(format!("Unknown path {path_id}")),
+    }
+  }
+  let path_count = selected_paths.len() as i32;
+  if path_count < path_min {
+    violations.push(format!(
+      "{path_count} paths selected but level {} requires at least {path_min}",
+      sheet.level
+    ));
+  }
+  if path_count > path_max {
+    violations.push(format!(
+      "{path_count} paths selected but level {} allows at most {path_max}",
+      sheet.level
+    ));
+  }
+  let mut character_paths: Vec<Path> =
+    paths.values().filter(|path| path.inherient.unwrap_or_default()).cloned().collect();
+  character_paths.append(&mut selected_paths);
+  let available_skill_ids: HashSet<ObjectId> =
+    character_paths.iter().flat_map(|path| path.skill_ids.iter().flatten()).cloned().collect();
+
+  let mut skill_counts: Vec<(Skill, i32)> = Vec::new();
+  for skill_id in &sheet.skills {
+    let Some(skill) = skills.get(&skill_id.to_string()) else {
+      violations.push(format!("Unknown skill {skill_id}"));
+      continue;
+    };
+    match skill_counts.iter_mut().find(|(counted, _)| counted.id == skill.id) {
+      Some((_, count)) => *count += 1,
+      None => skill_counts.push((skill.clone(), 1)),
+    }
+  }
+
+  let sheet_skills: Vec<Skill> =
+    sheet.skills.iter().filter_map(|skill_id| skills.get(&skill_id.to_string())).cloned().collect();
+  let untrained_modifiers = character_modifiers(sheet.level, &Default::default(), &sheet_skills);
+  let modifiers = character_modifiers(sheet.level, &sheet.training, &sheet_skills);
+
+  let mut selected_weight = 0;
+  for (skill, count) in &skill_counts {
+    if !available_skill_ids.contains(&skill.id) {
+      violations.push(format!("{} is not available from the character's paths", skill.title));
+    }
+    if *count > 1 && !skill.is_ranked() {
+      violations.push(format!("{} is not ranked but was learned {count} times", skill.title));
+    }
+    selected_weight += skill.weight() * count;
+  }
+
+  let mut weight_budget = 0;
+  for path in &character_paths {
+    let (constraints, path_budget) = path.selection_constraints();
+    weight_budget += path_budget;
+    for constraint in constraints {
+      let filter = &constraint.filter;
+      let weight: i32 = skill_counts
+        .iter()
+        .filter(|(skill, _)| skill.is_match(filter))
+        .map(|(skill, count)| skill.weight() * count)
+        .sum();
+      if weight < constraint.required_weight {
+        let skill_weight = filter.skill_filter.weight().max(1);
+        violations.push(format!(
+          "{} requires {} {} but only {} were chosen",
+          path.title,
+          constraint.required_weight / skill_weight,
+          filter.skill_filter,
+          weight / skill_weight
+        ));
+      }
+    }
+  }
+  let extra_features = (path_max - path_count.max(path_min)).max(0);
+  let features = modifiers.get(&ModifierClass::Feature) + extra_features;
+  weight_budget += 2 * features.max(0);
+  weight_budget += modifiers.get(&ModifierClass::MinorFeature).max(0);
+  if selected_weight > weight_budget {
+    violations.push(format!(
+      "Skills use {selected_weight} feature weight but only {weight_budget} is available"
+    ));
+  }
+
+  attribute_violations(&mut violations, sheet, &modifiers);
+  growth_violations(&mut violations, sheet, &untrained_modifiers);
+  return violations;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::rules::prelude::AttributeRanks;
+
+  fn sheet(capabilities: [i32; 4], defenses: [i32; 4]) -> CharacterSheet {
+    return CharacterSheet {
+      id: ObjectId::new(),
+      name: "Tester".into(),
+      level: 1,
+      attributes: AttributeRanks {
+        physique: Some(capabilities[0]),
+        warfare: Some(capabilities[1]),
+        spirit: Some(capabilities[2]),
+        manipulation: Some(capabilities[3]),
+        tenacity: Some(defenses[0]),
+        fortitude: Some(defenses[1]),
+        resolve: Some(defenses[2]),
+        insight: Some(defenses[3]),
+        ..AttributeRanks::default()
+      },
+      attribute_layers: None,
+      training: TrainingRanks::default(),
+      body: BodyStats { hp: 30, constitution: 4, speed: 6 },
+      paths: HashSet::new(),
+      skills: Vec::new(),
+      flows: None,
+      armor: None,
+      weapons: None,
+      inventory: None,
+      resistances: None,
+      expertise: None,
+      revision: None,
+    };
+  }
+
+  fn modifiers(rank_max: i32, attribute_ranks: i32, specializations: i32) -> ModifierSet {
+    let mut modifiers = ModifierSet::default();
+    modifiers.add_bonus(&ModifierClass::RankMax, rank_max);
+    modifiers.add_bonus(&ModifierClass::AttributeRank, attribute_ranks);
+    modifiers.add_bonus(&ModifierClass::CapabilitySpecialization, specializations);
+    modifiers.add_bonus(&ModifierClass::DefenseSpecialization, specializations);
+    return modifiers;
+  }
+
+  fn attributes(sheet: &CharacterSheet, modifiers: &ModifierSet) -> Vec<String> {
+    let mut violations: Vec<String> = Vec::new();
+    attribute_violations(&mut violations, sheet, modifiers);
+    return violations;
+  }
+
+  fn growth(sheet: &CharacterSheet, growth_ranks: i32) -> Vec<String> {
+    let mut modifiers = ModifierSet::default();
+    modifiers.add_bonus(&ModifierClass::GrowthRanks, growth_ranks);
+    let mut violations: Vec<String> = Vec::new();
+    growth_violations(&mut violations, sheet, &modifiers);
+    return violations;
+  }
+
+  #[test]
+  fn specialized_ranks_are_free() {
+    let violations = attributes(&sheet([3, 3, 0, 0], [0; 4]), &modifiers(2, 4, 2));
+    assert!(violations.is_empty(), "{violations:?}");
+  }
+
+  #[test]
+  fn unspecialized_ranks_are_all_spent() {
+    let violations = attributes(&sheet([3, 3, 0, 0], [0; 4]), &modifiers(3, 4, 2));
+    assert_eq!(
+      violations,
+      vec![
+        "6 capability ranks spent but only 4 are available",
+        "6 attribute ranks spent but only 4 are available",
+      ]
+    );
+  }
+
+  #[test]
+  fn too_many_specializations() {
+    let violations = attributes(&sheet([3, 3, 3, 0], [0; 4]), &modifiers(2, 10, 2));
+    assert_eq!(
+      violations,
+      vec!["3 capabilities are specialized but only 2 can be"]
+    );
+  }
+
+  #[test]
+  fn ranks_above_the_specialized_maximum() {
+    let violations = attributes(&sheet([0; 4], [4, 0, 0, 0]), &modifiers(2, 10, 1));
+    assert_eq!(
+      violations,
+      vec!["Tenacity has 3 ranks before specialization but the maximum is 2"]
+    );
+  }
+
+  fn layered(layers: Vec<(CharacterAttribute, i32, i32, i32)>) -> CharacterSheet {
+    let mut character = sheet([0; 4], [0; 4]);
+    let mut stored = character.attribute_layers();
+    for (attribute, ranks, specialization, enchantment) in layers {
+      if let Some(layer) = stored.iter_mut().find(|layer| layer.attribute == attribute) {
+        layer.layers = StatLayers { ranks, specialization, enchantment };
+      }
+    }
+    character.set_attribute_layers(stored);
+    return character;
+  }
+
+  #[test]
+  fn stored_specialization_is_used() {
+    let character = layered(vec![
+      (CharacterAttribute::Physique, 2, 1, 0),
+      (CharacterAttribute::Warfare, 2, 0, 0),
+    ]);
+    assert!(attributes(&character, &modifiers(2, 4, 1)).is_empty());
+    let character = layered(vec![
+      (CharacterAttribute::Physique, 3, 0, 0),
+      (CharacterAttribute::Warfare, 1, 0, 0),
+    ]);
+    assert_eq!(
+      attributes(&character, &modifiers(3, 3, 2)),
+      vec![
+        "4 capability ranks spent but only 3 are available",
+        "4 attribute ranks spent but only 3 are available",
+      ]
+    );
+  }
+
+  #[test]
+  fn enchantment_is_not_spent() {
+    let character = layered(vec![(CharacterAttribute::Spirit, 2, 0, 3)]);
+    assert!(attributes(&character, &modifiers(2, 2, 0)).is_empty());
+  }
+
+  #[test]
+  fn specialized_more_than_once() {
+    let character = layered(vec![(CharacterAttribute::Resolve, 1, 2, 0)]);
+    assert_eq!(
+      attributes(&character, &modifiers(2, 2, 2)),
+      vec!["Resolve can only be specialized once"]
+    );
+    assert!(character.validation_errors().contains(&"Resolve can only be specialized once".into()));
+  }
+
+  #[test]
+  fn growth_within_budget() {
+    let mut character = sheet([0; 4], [0; 4]);
+    character.training.expert = Some(1);
+    character.training.adept = Some(1);
+    assert!(growth(&character, 2).is_empty());
+  }
+
+  #[test]
+  fn growth_overspent_and_above_level() {
+    let mut character = sheet([0; 4], [0; 4]);
+    character.training.expert = Some(2);
+    character.training.endurance = Some(-1);
+    let violations = growth(&character, 0);
+    let above_level = "Expert training is rank 2 but cannot exceed the character level 1";
+    assert!(violations.contains(&above_level.into()));
+    assert!(violations.contains(&"Endurance training cannot be negative".into()));
+    assert!(violations.contains(&"1 growth ranks spent but level 1 only grants 0".into()));
+  }
+
+  #[test]
+  fn flow_training_needs_a_path() {
+    let mut character = sheet([0; 4], [0; 4]);
+    character.training.innate = Some(1);
+    let violations = growth(&character, 1);
+    assert_eq!(violations, vec!["Innate training requires a path granting Innate flow"]);
+  }
+
+  #[test]
+  fn legality_reports_attribute_overspend() {
+    let violations =
+      legality_violations(&sheet([5, 5, 5, 5], [0; 4]), &HashMap::new(), &HashMap::new());
+    assert!(violations.iter().any(|violation| violation.contains("capability ranks spent")));
+  }
+}
@@ -1,6 +1,9 @@
 pub mod component;
 
+mod derive;
 mod fixed;
+#[cfg(any(feature = "server", test))]
+mod legality;
 mod track;
 mod training;
 
@@ -8,5 +11,11 @@ pub mod prelude {
   // pub use super::track::{character_growth_track, training_growth_track};
   // pub use super::training::{CharacterBonus, TrainingGrowth};
   pub use super::component::ranks::RankDisplay;
-  pub use super::fixed::{BASE_DEFENSE, BASE_RESIST};
+  pub use super::derive::{
+    character_flows, character_modifiers, derive_stats, innate_pools, stored_pool_ranks,
+    DerivedStats, StatInputs,
+  };
+  #[cfg(feature = "server")]
+  pub use super::legality::legality_violations;
+  pub use super::fixed::{BASE_DEFENSE, BASE_RESIST, MAX_LEVEL, MIN_LEVEL};
 }
@@ -17,6 +17,17 @@ impl LevelTrack {
     }
     return modifiers;
   }
+
+  pub fn gained_at(level: i32) -> ModifierSet {
+    let mut modifiers = ModifierSet::default();
+    let Some(index) = usize::try_from(level - 1).ok() else {
+      return modifiers;
+    };
+    for (class, value) in level_bonuses().get(index).into_iter().flatten() {
+      modifiers.add_bonus(class, *value);
+    }
+    return modifiers;
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,227 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use dioxus::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use super::dice::{DiceGroup, Die};
+use super::roller::roll_die;
+use crate::character::prelude::{DamageClass, Resistances};
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DamageOptions {
+  pub bonus: i32,
+  pub boosted: bool,
+  pub critical: bool,
+  pub extra_dice: i32,
+}
+
+impl DamageOptions {
+  pub fn dice_change(&self) -> i32 {
+    return i32::from(self.boosted) + i32::from(self.critical) + self.extra_dice;
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DamageRoll {
+  pub dice: Vec<(Die, i32)>,
+  pub bonus: i32,
+  pub resistance: i32,
+  pub class: DamageClass,
+}
+
+impl fmt::Display for DamageRoll {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let faces: Vec<String> =
+      self.dice.iter().map(|(die, face)| format!("{die}:{face}")).collect();
+    write!(f, "{}", faces.join(" "))?;
+    if self.bonus != 0 {
+      write!(f, " {:+}", self.bonus)?;
+    }
+    if self.resistance != 0 {
+      write!(f, " = {} - {} resisted", self.raw(), self.resistance)?;
+    }
+    write!(f, " = {} {}", self.damage(), self.class)
+  }
+}
+
+impl DamageRoll {
+  pub fn raw(&self) -> i32 {
+    return self.dice.iter().map(|(_, face)| face).sum::<i32>() + self.bonus;
+  }
+
+  pub fn damage(&self) -> i32 {
+    return (self.raw() - self.resistance).max(0);
+  }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DamageDistribution {
+  pub outcomes: Vec<(i32, f64)>,
+}
+
+impl DamageDistribution {
+  pub fn expected(&self) -> f64 {
+    return self.outcomes.iter().map(|(damage, chance)| *damage as f64 * chance).sum();
+  }
+
+  pub fn min(&self) -> i32 {
+    return self.outcomes.first().map(|(damage, _)| *damage).unwrap_or_default();
+  }
+
+  pub fn max(&self) -> i32 {
+    return self.outcomes.last().map(|(damage, _)| *damage).unwrap_or_default();
+  }
+}
+
+pub fn largest_die(group: &DiceGroup) -> Option<Die> {
+  return group
+    .iter()
+    .filter(|set| set.amount > 0)
+    .map(|set| set.die.clone())
+    .max_by_key(|die| die.sides());
+}
+
+pub fn adjust_dice(group: &DiceGroup, change: i32) -> DiceGroup {
+  let mut dice: DiceGroup = group.iter().filter(|set| set.amount > 0).cloned().collect();
+  let Some(largest) = largest_die(&dice) else {
+    return dice;
+  };
+  if change > 0 {
+    if let Some(set) = dice.iter_mut().find(|set| set.die == largest) {
+      set.amount += change;
+    }
+    return dice;
+  }
+  for _ in 0..-change {
+    let Some(largest) = largest_die(&dice) else {
+      break;
+    };
+    if let Some(set) = dice.iter_mut().find(|set| set.die == largest) {
+      set.amount -= 1;
+    }
+    dice.retain(|set| set.amount > 0);
+  }
+  return dice;
+}
+
+pub fn resistance_to(resistances: &Resistances, class: &DamageClass) -> i32 {
+  let (_, resistance, _) = resistances.show_damage(class);
+  return resistance;
+}
+
+pub fn roll_damage(
+  group: &DiceGroup, class: &DamageClass, options: &DamageOptions, resistance: i32,
+  rng: &mut impl Rng,
+) -> DamageRoll {
+  let mut dice: Vec<(Die, i32)> = Vec::new();
+  for set in adjust_dice(group, options.dice_change()) {
+    for _ in 0..set.amount {
+      dice.push((set.die.clone(), roll_die(set.die.sides(), rng)));
+    }
+  }
+  return DamageRoll { dice, bonus: options.bonus, resistance, class: class.clone() };
+}
+
+pub fn damage_distribution(
+  group: &DiceGroup, options: &DamageOptions, resistance: i32,
+) -> DamageDistribution {
+  let mut totals: BTreeMap<i32, f64> = BTreeMap::from([(0, 1.0)]);
+  for set in adjust_dice(group, options.dice_change()) {
+    let sides = set.die.sides();
+    for _ in 0..set.amount {
+      let mut next: BTreeMap<i32, f64> = BTreeMap::new();
+      for (total, chance) in &totals {
+        for face in 1..=sides {
+          *next.entry(total + face).or_default() += chance / sides as f64;
+        }
+      }
+      totals = next;
+    }
+  }
+  let mut outcomes: BTreeMap<i32, f64> = BTreeMap::new();
+  for (total, chance) in totals {
+    *outcomes.entry((total + options.bonus - resistance).max(0)).or_default() += chance;
+  }
+  return DamageDistribution { outcomes: outcomes.into_iter().collect() };
+}
+
+#[component]
+pub fn DamageSummary(group: DiceGroup, bonus: i32, resistance: i32) -> Element {
+  let variants: Vec<(&str, DamageDistribution)> = [
+    ("Damage", false, false),
+    ("Boosted", true, false),
+    ("Critical", false, true),
+    ("Both", true, true),
+  ]
+  .into_iter()
+  .map(|(title, boosted, critical)| {
+    let options = DamageOptions { bonus, boosted, critical, extra_dice: 0 };
+    (title, damage_distribution(&group, &options, resistance))
+  })
+  .collect();
+  rsx! {
+    div {
+      class: "column mini-text no-print",
+      for (title, distribution) in variants {
+        div {
+          "{title} {distribution.expected():.1} ({distribution.min()}-{distribution.max()})"
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::rules::dice::DiceSet;
+
+  fn dice(sets: &[(i32, Die)]) -> DiceGroup {
+    return sets.iter().map(|(amount, die)| DiceSet { amount: *amount, die: die.clone() }).collect();
+  }
+
+  fn close(left: f64, right: f64) -> bool {
+    return (left - right).abs() < 1e-9;
+  }
+
+  #[test]
+  fn boosts_and_criticals_add_the_largest_die() {
+    let group = dice(&[(1, Die::D6), (2, Die::D8), (0, Die::D12)]);
+    let options = DamageOptions { boosted: true, critical: true, ..Default::default() };
+    assert_eq!(options.dice_change(), 2);
+    let adjusted = adjust_dice(&group, options.dice_change());
+    assert_eq!(adjusted, dice(&[(1, Die::D6), (4, Die::D8)]));
+  }
+
+  #[test]
+  fn reductions_remove_the_largest_die_first() {
+    let group = dice(&[(1, Die::D6), (2, Die::D8), (1, Die::D4)]);
+    assert_eq!(adjust_dice(&group, -1), dice(&[(1, Die::D6), (1, Die::D8), (1, Die::D4)]));
+    assert_eq!(adjust_dice(&group, -2), dice(&[(1, Die::D6), (1, Die::D4)]));
+    assert_eq!(adjust_dice(&group, -3), dice(&[(1, Die::D4)]));
+    assert!(adjust_dice(&group, -10).is_empty());
+    assert_eq!(adjust_dice(&group, 0), group);
+  }
+
+  #[test]
+  fn distribution_matches_the_dice_and_bonus() {
+    let options = DamageOptions { bonus: 2, ..Default::default() };
+    let distribution = damage_distribution(&dice(&[(2, Die::D4)]), &options, 0);
+    assert_eq!((distribution.min(), distribution.max()), (4, 10));
+    assert!(close(distribution.expected(), 7.0));
+    let total: f64 = distribution.outcomes.iter().map(|(_, chance)| chance).sum();
+    assert!(close(total, 1.0));
+  }
+
+  #[test]
+  fn resistance_floors_damage_at_zero() {
+    let distribution = damage_distribution(&dice(&[(1, Die::D6)]), &DamageOptions::default(), 4);
+    assert_eq!(distribution.min(), 0);
+    assert_eq!(distribution.max(), 2);
+    assert!(close(distribution.outcomes[0].1, 4.0 / 6.0));
+    assert!(close(distribution.expected(), 0.5));
+  }
+}
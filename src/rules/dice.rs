@@ -1,6 +1,7 @@
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::str::FromStr;
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub enum Die {
@@ -29,6 +30,27 @@ impl fmt::Display for Die {
   }
 }
 
+impl Die {
+  pub fn ordered() -> Vec<Die> {
+    return vec![Die::D3, Die::D4, Die::D6, Die::D8, Die::D10, Die::D12];
+  }
+
+  pub fn from_sides(sides: i32) -> Option<Die> {
+    return Die::ordered().into_iter().find(|die| die.sides() == sides);
+  }
+
+  pub fn sides(&self) -> i32 {
+    return match self {
+      Die::D3 => 3,
+      Die::D4 => 4,
+      Die::D6 => 6,
+      Die::D8 => 8,
+      Die::D10 => 10,
+      Die::D12 => 12,
+    };
+  }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct DiceSet {
   pub amount: i32,
@@ -43,12 +65,169 @@ impl fmt::Display for DiceSet {
 
 pub type DiceGroup = Vec<DiceSet>;
 
+pub const MAX_DICE: i32 = 100;
+pub const MAX_MODIFIER: i32 = 1000;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct DiceExpression {
+  pub dice: DiceGroup,
+  pub modifier: i32,
+}
+
+impl fmt::Display for DiceExpression {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let dice: Vec<String> = self.dice.iter().map(|set| set.to_string()).collect();
+    let dice = dice.join(" + ");
+    return match (dice.is_empty(), self.modifier) {
+      (true, modifier) => write!(f, "{modifier}"),
+      (false, 0) => write!(f, "{dice}"),
+      (false, modifier) if modifier > 0 => write!(f, "{dice} + {modifier}"),
+      (false, modifier) => write!(f, "{dice} - {}", -modifier),
+    };
+  }
+}
+
+impl FromStr for DiceExpression {
+  type Err = String;
+
+  fn from_str(text: &str) -> Result<Self, Self::Err> {
+    let compact: String =
+      text.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_lowercase();
+    if compact.is_empty() {
+      return Err("Enter dice like 2d8 + 1d6 + 3".into());
+    }
+    let mut expression = DiceExpression::default();
+    let mut term = String::new();
+    let mut sign = 1;
+    for (index, character) in compact.chars().enumerate() {
+      match character {
+        '+' | '-' if index > 0 => {
+          expression.add_term(&term, sign)?;
+          term.clear();
+          sign = if character == '-' { -1 } else { 1 };
+        },
+        '-' => sign = -1,
+        '+' => {},
+        _ => term.push(character),
+      }
+    }
+    expression.add_term(&term, sign)?;
+    return Ok(expression);
+  }
+}
+
+impl DiceExpression {
+  pub fn new(dice: DiceGroup, modifier: i32) -> Self {
+    return Self { dice, modifier };
+  }
+
+  pub fn dice_count(&self) -> i32 {
+    return self.dice.iter().fold(0, |total: i32, set| total.saturating_add(set.amount.max(0)));
+  }
+
+  pub fn limit_error(&self) -> Option<String> {
+    let count = self.dice_count();
+    if count > MAX_DICE {
+      return Some(format!("{count} dice is too many, roll at most {MAX_DICE}"));
+    }
+    if self.modifier.unsigned_abs() > MAX_MODIFIER.unsigned_abs() {
+      return Some(format!("{} is too large a modifier, use at most {MAX_MODIFIER}", self.modifier));
+    }
+    return None;
+  }
+
+  fn add_term(&mut self, term: &str, sign: i32) -> Result<(), String> {
+    if term.is_empty() {
+      return Err("Each + or - needs dice or a number after it".into());
+    }
+    let Some((amount, sides)) = term.split_once('d') else {
+      let value = term.parse::<i32>().map_err(|_| format!("{term} is not dice or a number"))?;
+      self.modifier = self.modifier.saturating_add(sign * value);
+      return self.limit_error().map_or(Ok(()), Err);
+    };
+    if sign < 0 {
+      return Err(format!("{term} cannot be subtracted, only flat modifiers can"));
+    }
+    let amount = match amount {
+      "" => 1,
+      amount => amount.parse::<i32>().map_err(|_| format!("{amount} is not a number of dice"))?,
+    };
+    if amount < 1 {
+      return Err(format!("{term} needs at least one die"));
+    }
+    let sides = sides.parse::<i32>().map_err(|_| format!("d{sides} is not a die"))?;
+    let supported: Vec<String> = Die::ordered().iter().map(|die| die.to_string()).collect();
+    let Some(die) = Die::from_sides(sides) else {
+      return Err(format!("d{sides} is not supported, use {}", supported.join(", ")));
+    };
+    self.dice.push(DiceSet { amount, die });
+    return self.limit_error().map_or(Ok(()), Err);
+  }
+}
+
 #[component]
-pub fn DiceGroupEntry(group: ReadSignal<DiceGroup>) -> Element {
-  let display = group
-    .iter()
-    .map(|d| d.to_string())
-    .collect::<Vec<String>>()
-    .join(" + ");
+pub fn DiceGroupEntry(group: ReadSignal<DiceGroup>, #[props(default)] modifier: i32) -> Element {
+  let display = DiceExpression::new(group(), modifier);
   return rsx!( "{display}" );
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn parse(text: &str) -> Result<DiceExpression, String> {
+    return text.parse::<DiceExpression>();
+  }
+
+  #[test]
+  fn round_trips() {
+    let cases = [
+      ("d10", "1d10"),
+      ("2d8 + 1d6 - 3", "2d8 + 1d6 - 3"),
+      ("2D8+1d6+3", "2d8 + 1d6 + 3"),
+      ("-2 + 1d4", "1d4 - 2"),
+      ("3", "3"),
+      ("1d6 + 2 - 2", "1d6"),
+    ];
+    for (text, formatted) in cases {
+      let expression = parse(text).unwrap();
+      assert_eq!(expression.to_string(), formatted);
+      assert_eq!(parse(formatted), Ok(expression));
+    }
+  }
+
+  #[test]
+  fn parses_dice_and_modifier() {
+    let expression = parse("2d8 + 1d6 - 3").unwrap();
+    let dice = vec![DiceSet { amount: 2, die: Die::D8 }, DiceSet { amount: 1, die: Die::D6 }];
+    assert_eq!(expression, DiceExpression::new(dice, -3));
+    assert_eq!(expression.dice_count(), 3);
+  }
+
+  #[test]
+  fn error_messages() {
+    let cases = [
+      ("", "Enter dice like 2d8 + 1d6 + 3"),
+      ("d7", "d7 is not supported, use d3, d4, d6, d8, d10, d12"),
+      ("3+", "Each + or - needs dice or a number after it"),
+      ("-1d6", "1d6 cannot be subtracted, only flat modifiers can"),
+      ("0d6", "0d6 needs at least one die"),
+      ("xd6", "x is not a number of dice"),
+      ("abc", "abc is not dice or a number"),
+      ("1dx", "dx is not a die"),
+    ];
+    for (text, error) in cases {
+      assert_eq!(parse(text), Err(error.to_string()), "parsing {text:?}");
+    }
+  }
+
+  #[test]
+  fn rejects_too_many_dice() {
+    assert!(parse("100d6").is_ok());
+    assert_eq!(parse("101d6"), Err("101 dice is too many, roll at most 100".to_string()));
+    assert_eq!(parse("60d6 + 60d8"), Err("120 dice is too many, roll at most 100".to_string()));
+    assert!(parse("2147483647d12").is_err());
+    assert!(parse("99999999999d6").is_err());
+    assert!(parse("1d6 + 5000").is_err());
+  }
+}
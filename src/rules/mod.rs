@@ -1,19 +1,32 @@
 pub mod components;
+mod damage;
 mod dice;
+mod odds;
 mod roll;
+mod roller;
 mod rulebook;
 mod section;
 mod snippet;
 mod stack;
 mod stat_block;
+mod table;
 mod tier;
 
 pub(crate) mod prelude {
-  pub(crate) use super::dice::{DiceGroup, DiceGroupEntry};
+  pub(crate) use super::damage::{
+    resistance_to, roll_damage, DamageOptions, DamageRoll, DamageSummary,
+  };
+  pub(crate) use super::dice::{DiceExpression, DiceGroup, DiceGroupEntry, MAX_MODIFIER};
+  pub(crate) use super::odds::{roll_odds, OddsContext, RollOdds};
+  pub(crate) use super::roll::{Modifier, Roll, RollClass, RollResult};
+  pub(crate) use super::roller::{
+    roll_check, roll_expression, seeded_rng, CheckRoll, ExpressionRoll,
+  };
   pub(crate) use super::rulebook::MainRulesThread;
   pub(crate) use super::section::{RulesSectionSet, RuleSections, rule_sections_from_blurb_certain, rule_sections_from_blurb, rule_sections_from_block};
-  pub(crate) use super::snippet::{RulesBlock};
+  pub(crate) use super::snippet::{RulesBlock, Snippet};
   pub(crate) use super::stack::{Stack, PropertyDetail, RuleStacks, RulesStackDetail,rules_stack_from_blurb};
+  pub(crate) use super::table::{TableRoll, TableRollContext};
   pub(crate) use super::tier::Tier;
   pub(crate) use super::stat_block::{AttributeRanks, CapabilityBlock, DefenseBlock};
 }
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use dioxus::prelude::*;
+use once_cell::sync::Lazy;
+
+use super::roll::{Modifier, Roll, RollClass, RollResult};
+use super::roller::{CheckRoll, CHECK_SIDES};
+use super::stat_block::AttributeRanks;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RollOdds {
+  pub results: Vec<(RollResult, f64)>,
+}
+
+impl fmt::Display for RollOdds {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "Hit {:.1}% · Crit {:.1}% · Botch {:.1}%",
+      100.0 * self.hit(),
+      100.0 * self.critical(),
+      100.0 * self.botch()
+    )
+  }
+}
+
+impl RollOdds {
+  pub fn chance(&self, result: &RollResult) -> f64 {
+    return self
+      .results
+      .iter()
+      .filter(|(outcome, _)| outcome == result)
+      .map(|(_, chance)| chance)
+      .sum();
+  }
+
+  pub fn hit(&self) -> f64 {
+    return [RollResult::Hit, RollResult::Success]
+      .iter()
+      .map(|result| self.chance(result))
+      .sum::<f64>()
+      + self.critical();
+  }
+
+  pub fn critical(&self) -> f64 {
+    return self.chance(&RollResult::Critical) + self.chance(&RollResult::CriticalSuccess);
+  }
+
+  pub fn botch(&self) -> f64 {
+    return self.chance(&RollResult::Botch) + self.chance(&RollResult::CriticalFailure);
+  }
+}
+
+impl Modifier {
+  pub fn combine(&self, other: &Modifier) -> Modifier {
+    return match (self, other) {
+      (Modifier::Normal, modifier) | (modifier, Modifier::Normal) => modifier.clone(),
+      (first, second) if first == second => first.clone(),
+      _ => Modifier::Normal,
+    };
+  }
+}
+
+type KeptCounts = (Vec<(Vec<i32>, u64)>, u64);
+
+static NORMAL_COUNTS: Lazy<KeptCounts> = Lazy::new(|| kept_counts(&Modifier::Normal));
+static ADVANTAGE_COUNTS: Lazy<KeptCounts> = Lazy::new(|| kept_counts(&Modifier::Advantage));
+static DISADVANTAGE_COUNTS: Lazy<KeptCounts> =
+  Lazy::new(|| kept_counts(&Modifier::Disadvantage));
+
+fn kept_counts(mode: &Modifier) -> KeptCounts {
+  let dice = u32::try_from(mode.dice()).unwrap_or_default();
+  let sides = CHECK_SIDES as u64;
+  let outcomes = sides.pow(dice);
+  let mut counts: HashMap<Vec<i32>, u64> = HashMap::new();
+  for outcome in 0..outcomes {
+    let mut remaining = outcome;
+    let mut faces: Vec<i32> = Vec::new();
+    for _ in 0..dice {
+      faces.push((remaining % sides) as i32 + 1);
+      remaining /= sides;
+    }
+    let mut kept = CheckRoll::from_faces(faces, 0, mode.clone()).kept_faces();
+    kept.sort();
+    *counts.entry(kept).or_default() += 1;
+  }
+  return (counts.into_iter().collect(), outcomes);
+}
+
+fn cached_counts(mode: &Modifier) -> &'static KeptCounts {
+  return match mode {
+    Modifier::Normal => &NORMAL_COUNTS,
+    Modifier::Advantage => &ADVANTAGE_COUNTS,
+    Modifier::Disadvantage => &DISADVANTAGE_COUNTS,
+  };
+}
+
+pub fn roll_odds(bonus: i32, mode: &Modifier, class: &RollClass, target: i32) -> RollOdds {
+  let (counts, outcomes) = cached_counts(mode);
+  let mut results: Vec<(RollResult, f64)> = Vec::new();
+  for (kept, count) in counts {
+    let result =
+      CheckRoll::from_faces(kept.clone(), bonus, Modifier::Normal).result(class, target);
+    let chance = *count as f64 / *outcomes as f64;
+    match results.iter_mut().find(|(outcome, _)| outcome == &result) {
+      Some((_, total)) => *total += chance,
+      None => results.push((result, chance)),
+    }
+  }
+  return RollOdds { results };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OddsContext {
+  pub attributes: Memo<AttributeRanks>,
+  pub defense: Signal<Option<i32>>,
+  pub mode: Signal<Modifier>,
+}
+
+impl OddsContext {
+  pub fn odds(&self, roll: &Roll) -> Option<RollOdds> {
+    let defense = (self.defense)()?;
+    let bonus = match (&roll.class, &roll.capability) {
+      (RollClass::LuckCheck, _) => 0,
+      (_, Some(capability)) => (self.attributes)().capability(capability).unwrap_or_default(),
+      (_, None) => return None,
+    };
+    let mode = (self.mode)().combine(roll.modifier.as_ref().unwrap_or(&Modifier::Normal));
+    return Some(roll_odds(bonus, &mode, &roll.class, defense));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "{actual} is not {expected}");
+  }
+
+  #[test]
+  fn triples_and_botches_on_3d6() {
+    let odds = roll_odds(0, &Modifier::Normal, &RollClass::Attack, 100);
+    assert_close(odds.critical(), 0.0);
+    assert_close(odds.hit(), 5.0 / 216.0);
+    assert_close(odds.botch(), 1.0 / 216.0);
+    let odds = roll_odds(0, &Modifier::Normal, &RollClass::Attack, 0);
+    assert_close(odds.critical() + odds.botch(), 1.0 / 36.0);
+  }
+
+  #[test]
+  fn hit_odds_against_eleven() {
+    let odds = roll_odds(0, &Modifier::Normal, &RollClass::Attack, 11);
+    assert_close(odds.hit(), 110.0 / 216.0);
+    assert_close(odds.critical(), 3.0 / 216.0);
+    assert_close(odds.botch(), 1.0 / 216.0);
+    assert_close(odds.results.iter().map(|(_, chance)| chance).sum(), 1.0);
+  }
+
+  #[test]
+  fn advantage_improves_odds() {
+    let normal = roll_odds(0, &Modifier::Normal, &RollClass::Attack, 11);
+    let advantage = roll_odds(0, &Modifier::Advantage, &RollClass::Attack, 11);
+    let disadvantage = roll_odds(0, &Modifier::Disadvantage, &RollClass::Attack, 11);
+    assert!(advantage.hit() > normal.hit());
+    assert!(disadvantage.hit() < normal.hit());
+    assert_close(advantage.results.iter().map(|(_, chance)| chance).sum(), 1.0);
+  }
+
+  #[test]
+  fn opposite_modes_cancel() {
+    assert_eq!(Modifier::Advantage.combine(&Modifier::Disadvantage), Modifier::Normal);
+    assert_eq!(Modifier::Normal.combine(&Modifier::Advantage), Modifier::Advantage);
+  }
+}
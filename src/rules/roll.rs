@@ -69,8 +69,37 @@ impl fmt::Display for RollClass {
   }
 }
 
+impl RollClass {
+  pub fn ordered() -> Vec<RollClass> {
+    return vec![RollClass::Attack, RollClass::Check, RollClass::LuckCheck];
+  }
+}
+
+impl fmt::Display for Modifier {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        Modifier::Normal => "Normal",
+        Modifier::Advantage => "Advantage",
+        Modifier::Disadvantage => "Disadvantage",
+      }
+    )
+  }
+}
+
+impl Modifier {
+  pub fn ordered() -> Vec<Modifier> {
+    return vec![Modifier::Normal, Modifier::Advantage, Modifier::Disadvantage];
+  }
+}
+
 #[component]
 pub fn RollSnippet(roll: Roll) -> Element {
+  let odds = try_use_context::<OddsContext>().and_then(|context| context.odds(&roll));
+  let table = try_use_context::<TableRollContext>();
+  let table_roll = roll.clone();
   let defense = match (&roll.defense, &roll.alternate_defense) {
     (_, Some(defense)) => Some(defense.clone()),
     (Some(defense), _) => Some(defense.to_string()),
@@ -112,6 +141,16 @@ pub fn RollSnippet(roll: Roll) -> Element {
       _ => rsx! { span { " against {article} target" } },
     }
     span { "." }
+    if let Some(odds) = odds {
+      span { class: "highlight no-print", " ({odds})" }
+    }
+    if let Some(table) = table {
+      span {
+        class: "chip no-print",
+        onclick: move |_| table.on_roll.call(TableRoll::Check(table_roll.clone())),
+        "Roll"
+      }
+    }
   };
 }
 
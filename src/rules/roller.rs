@@ -0,0 +1,277 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::dice::{DiceExpression, Die};
+use super::roll::{Modifier, RollClass, RollResult};
+
+pub const CHECK_DICE: usize = 3;
+pub const MODIFIED_DICE: usize = 5;
+pub const CHECK_SIDES: i32 = 6;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RolledDie {
+  pub face: i32,
+  pub original: Option<i32>,
+  pub kept: bool,
+}
+
+impl RolledDie {
+  pub fn rerolled(&self) -> bool {
+    return self.original.is_some();
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckRoll {
+  pub dice: Vec<RolledDie>,
+  pub bonus: i32,
+  pub mode: Modifier,
+}
+
+impl fmt::Display for CheckRoll {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let faces: Vec<String> = self
+      .dice
+      .iter()
+      .map(|die| match die.kept {
+        true => format!("[{}]", die.face),
+        false => die.face.to_string(),
+      })
+      .collect();
+    write!(f, "{} {:+} = {}", faces.join(" "), self.bonus, self.total())
+  }
+}
+
+impl Modifier {
+  pub fn dice(&self) -> usize {
+    return match self {
+      Modifier::Normal => CHECK_DICE,
+      Modifier::Advantage | Modifier::Disadvantage => MODIFIED_DICE,
+    };
+  }
+}
+
+impl CheckRoll {
+  pub fn from_faces(faces: Vec<i32>, bonus: i32, mode: Modifier) -> CheckRoll {
+    let dice = faces.into_iter().map(|face| RolledDie { face, original: None, kept: false });
+    let mut roll = CheckRoll { dice: dice.collect(), bonus, mode };
+    roll.select_kept();
+    return roll;
+  }
+
+  fn advantage_triple(&self) -> Option<i32> {
+    return (2..=CHECK_SIDES)
+      .rev()
+      .find(|face| self.dice.iter().filter(|die| die.face == *face).count() >= CHECK_DICE);
+  }
+
+  fn select_kept(&mut self) {
+    let mut order: Vec<usize> = (0..self.dice.len()).collect();
+    order.sort_by_key(|index| self.dice[*index].face);
+    if self.mode == Modifier::Advantage {
+      order.reverse();
+      // A triple other than 1s is at least a hit and a critical when it succeeds,
+      // so with advantage it is always picked over the highest sum.
+      if let Some(face) = self.advantage_triple() {
+        order.sort_by_key(|index| self.dice[*index].face != face);
+      }
+    }
+    for die in self.dice.iter_mut() {
+      die.kept = false;
+    }
+    for index in order.into_iter().take(CHECK_DICE) {
+      self.dice[index].kept = true;
+    }
+  }
+
+  pub fn kept_faces(&self) -> Vec<i32> {
+    return self.dice.iter().filter(|die| die.kept).map(|die| die.face).collect();
+  }
+
+  pub fn dice_total(&self) -> i32 {
+    return self.kept_faces().iter().sum();
+  }
+
+  pub fn total(&self) -> i32 {
+    return self.dice_total() + self.bonus;
+  }
+
+  pub fn is_triple(&self) -> bool {
+    let faces = self.kept_faces();
+    return faces.len() == CHECK_DICE && faces.iter().all(|face| *face == faces[0]);
+  }
+
+  pub fn is_botch(&self) -> bool {
+    return self.is_triple() && self.kept_faces()[0] == 1;
+  }
+
+  pub fn can_reroll(&self, index: usize) -> bool {
+    return self.dice.get(index).is_some_and(|die| !die.rerolled());
+  }
+
+  pub fn reroll(&mut self, index: usize, rng: &mut impl Rng) -> Result<i32, String> {
+    let Some(die) = self.dice.get_mut(index) else {
+      return Err(format!("There is no die {} to re-roll", index + 1));
+    };
+    if die.rerolled() {
+      return Err(format!("Die {} has already been re-rolled", index + 1));
+    }
+    die.original = Some(die.face);
+    die.face = roll_die(CHECK_SIDES, rng);
+    let face = die.face;
+    self.select_kept();
+    return Ok(face);
+  }
+
+  pub fn result(&self, class: &RollClass, target: i32) -> RollResult {
+    let succeeded = self.total() >= target;
+    return match (class, self.is_botch(), self.is_triple(), succeeded) {
+      (RollClass::Attack, true, _, _) => RollResult::Botch,
+      (RollClass::Attack, _, true, true) => RollResult::Critical,
+      (RollClass::Attack, _, true, false) | (RollClass::Attack, _, _, true) => RollResult::Hit,
+      (RollClass::Attack, _, _, false) => RollResult::Miss,
+      (_, true, _, _) => RollResult::CriticalFailure,
+      (_, _, true, true) => RollResult::CriticalSuccess,
+      (_, _, true, false) | (_, _, _, true) => RollResult::Success,
+      (_, _, _, false) => RollResult::Failure,
+    };
+  }
+}
+
+pub fn seeded_rng(seed: u64) -> StdRng {
+  return StdRng::seed_from_u64(seed);
+}
+
+pub fn roll_die(sides: i32, rng: &mut impl Rng) -> i32 {
+  return rng.random_range(1..=sides.max(1));
+}
+
+pub fn roll_check(bonus: i32, mode: &Modifier, rng: &mut impl Rng) -> CheckRoll {
+  let faces: Vec<i32> = (0..mode.dice()).map(|_| roll_die(CHECK_SIDES, rng)).collect();
+  return CheckRoll::from_faces(faces, bonus, mode.clone());
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpressionRoll {
+  pub expression: DiceExpression,
+  pub dice: Vec<(Die, i32)>,
+}
+
+impl fmt::Display for ExpressionRoll {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let faces: Vec<String> =
+      self.dice.iter().map(|(die, face)| format!("{die}:{face}")).collect();
+    write!(f, "{}", faces.join(" "))?;
+    if self.expression.modifier != 0 {
+      write!(f, " {:+}", self.expression.modifier)?;
+    }
+    write!(f, " = {}", self.total())
+  }
+}
+
+impl ExpressionRoll {
+  pub fn total(&self) -> i32 {
+    return self.dice.iter().map(|(_, face)| face).sum::<i32>() + self.expression.modifier;
+  }
+}
+
+pub fn roll_expression(expression: &DiceExpression, rng: &mut impl Rng) -> ExpressionRoll {
+  let mut dice: Vec<(Die, i32)> = Vec::new();
+  for set in &expression.dice {
+    for _ in 0..set.amount {
+      dice.push((set.die.clone(), roll_die(set.die.sides(), rng)));
+    }
+  }
+  return ExpressionRoll { expression: expression.clone(), dice };
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn advantage_keeps_highest_three() {
+    let roll = CheckRoll::from_faces(vec![1, 6, 2, 5, 4], 0, Modifier::Advantage);
+    assert_eq!(roll.kept_faces(), vec![6, 5, 4]);
+    assert_eq!(roll.total(), 15);
+  }
+
+  #[test]
+  fn disadvantage_keeps_lowest_three() {
+    let roll = CheckRoll::from_faces(vec![1, 6, 2, 5, 4], 0, Modifier::Disadvantage);
+    assert_eq!(roll.kept_faces(), vec![1, 2, 4]);
+    assert_eq!(roll.total(), 7);
+  }
+
+  #[test]
+  fn advantage_prefers_a_triple() {
+    let roll = CheckRoll::from_faces(vec![5, 5, 5, 6, 1], 0, Modifier::Advantage);
+    assert_eq!(roll.kept_faces(), vec![5, 5, 5]);
+    assert!(roll.is_triple());
+    let ones = CheckRoll::from_faces(vec![1, 1, 1, 6, 2], 0, Modifier::Advantage);
+    assert_eq!(ones.kept_faces(), vec![1, 6, 2]);
+    assert!(!ones.is_botch());
+  }
+
+  #[test]
+  fn triples_and_botches() {
+    let triple = CheckRoll::from_faces(vec![3, 3, 3], 0, Modifier::Normal);
+    assert!(triple.is_triple());
+    assert!(!triple.is_botch());
+    let botch = CheckRoll::from_faces(vec![1, 1, 1], 0, Modifier::Normal);
+    assert!(botch.is_triple());
+    assert!(botch.is_botch());
+    let plain = CheckRoll::from_faces(vec![1, 1, 2], 0, Modifier::Normal);
+    assert!(!plain.is_triple());
+    assert!(!plain.is_botch());
+  }
+
+  #[test]
+  fn failed_triple_still_hits() {
+    let roll = CheckRoll::from_faces(vec![2, 2, 2], 0, Modifier::Normal);
+    assert_eq!(roll.result(&RollClass::Attack, 10), RollResult::Hit);
+    assert_eq!(roll.result(&RollClass::Check, 10), RollResult::Success);
+    let roll = CheckRoll::from_faces(vec![4, 4, 4], 0, Modifier::Normal);
+    assert_eq!(roll.result(&RollClass::Attack, 10), RollResult::Critical);
+    assert_eq!(roll.result(&RollClass::Check, 10), RollResult::CriticalSuccess);
+  }
+
+  #[test]
+  fn triple_ones_botch() {
+    let roll = CheckRoll::from_faces(vec![1, 1, 1], 20, Modifier::Normal);
+    assert_eq!(roll.result(&RollClass::Attack, 10), RollResult::Botch);
+    assert_eq!(roll.result(&RollClass::Check, 10), RollResult::CriticalFailure);
+  }
+
+  #[test]
+  fn plain_hits_and_misses() {
+    let roll = CheckRoll::from_faces(vec![3, 4, 5], 1, Modifier::Normal);
+    assert_eq!(roll.result(&RollClass::Attack, 13), RollResult::Hit);
+    assert_eq!(roll.result(&RollClass::Attack, 14), RollResult::Miss);
+  }
+
+  #[test]
+  fn reroll_only_once() {
+    let mut roll = CheckRoll::from_faces(vec![2, 3, 4], 0, Modifier::Normal);
+    let mut rng = seeded_rng(1);
+    assert!(roll.reroll(1, &mut rng).is_ok());
+    assert_eq!(roll.dice[1].original, Some(3));
+    assert!(!roll.can_reroll(1));
+    assert_eq!(roll.reroll(1, &mut rng), Err("Die 2 has already been re-rolled".to_string()));
+    assert!(roll.reroll(3, &mut rng).is_err());
+    assert!(roll.can_reroll(0));
+  }
+
+  #[test]
+  fn seeded_roll_is_reproducible() {
+    let roll = roll_check(2, &Modifier::Normal, &mut seeded_rng(7));
+    assert_eq!(roll, roll_check(2, &Modifier::Normal, &mut seeded_rng(7)));
+    assert_eq!(roll.kept_faces(), vec![3, 1, 1]);
+    assert_eq!(roll.total(), 7);
+  }
+}
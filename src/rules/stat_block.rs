@@ -42,6 +42,42 @@ fn unpack_to_tuple<T: Display>( value: &Option<i32>, id: T ) -> Option<(String,
 }
 
 impl AttributeRanks {
+  pub fn rank(&self, attribute: &CharacterAttribute) -> Option<i32> {
+    return match attribute {
+      CharacterAttribute::Physique => self.physique,
+      CharacterAttribute::Warfare => self.warfare,
+      CharacterAttribute::Spirit => self.spirit,
+      CharacterAttribute::Manipulation => self.manipulation,
+      CharacterAttribute::Tenacity => self.tenacity,
+      CharacterAttribute::Fortitude => self.fortitude,
+      CharacterAttribute::Resolve => self.resolve,
+      CharacterAttribute::Insight => self.insight,
+    };
+  }
+
+  pub fn capability(&self, capability: &Capability) -> Option<i32> {
+    return match capability {
+      Capability::Physique => self.physique,
+      Capability::Warfare => self.warfare,
+      Capability::Spirit => self.spirit,
+      Capability::Manipulation => self.manipulation,
+    };
+  }
+
+  pub fn set_rank(&mut self, attribute: &CharacterAttribute, rank: Option<i32>) {
+    let field = match attribute {
+      CharacterAttribute::Physique => &mut self.physique,
+      CharacterAttribute::Warfare => &mut self.warfare,
+      CharacterAttribute::Spirit => &mut self.spirit,
+      CharacterAttribute::Manipulation => &mut self.manipulation,
+      CharacterAttribute::Tenacity => &mut self.tenacity,
+      CharacterAttribute::Fortitude => &mut self.fortitude,
+      CharacterAttribute::Resolve => &mut self.resolve,
+      CharacterAttribute::Insight => &mut self.insight,
+    };
+    *field = rank;
+  }
+
   pub fn update_dodge_with_bulk(&mut self, bulk: i32, slow: i32) -> (bool, i32) {
     let tenacity = self.tenacity.unwrap_or(0);
     let net_tenacity = tenacity - bulk;
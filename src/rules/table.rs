@@ -0,0 +1,15 @@
+use bson::oid::ObjectId;
+use dioxus::prelude::*;
+
+use super::roll::Roll;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableRoll {
+  Check(Roll),
+  Attack { weapon_id: ObjectId },
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct TableRollContext {
+  pub on_roll: Callback<TableRoll>,
+}
@@ -21,3 +21,13 @@ impl fmt::Display for Tier {
     )
   }
 }
+
+impl Tier {
+  pub fn ordered() -> Vec<Tier> {
+    return vec![
+      Tier::Initiate,
+      Tier::Journeyman,
+      Tier::Master,
+    ];
+  }
+}
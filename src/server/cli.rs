@@ -0,0 +1,56 @@
+use super::integrity::check_compendium_integrity;
+use super::skill::get_skill_map;
+use crate::skill::prelude::lint_skills;
+
+async fn print_integrity() -> i32 {
+  let report = match check_compendium_integrity().await {
+    Ok(report) => report,
+    Err(error) => {
+      eprintln!("Unable to check integrity: {error}");
+      return 2;
+    },
+  };
+  for issue in report.issues.iter() {
+    println!("{}: {issue}", issue.kind);
+  }
+  println!("Checked {} documents, found {} issues", report.documents, report.issues.len());
+  return if report.issues.is_empty() { 0 } else { 1 };
+}
+
+async fn print_content_lints() -> i32 {
+  let skills = match get_skill_map().await {
+    Ok(skills) => skills.into_values().collect::<Vec<_>>(),
+    Err(error) => {
+      eprintln!("Unable to load skills: {error}");
+      return 2;
+    },
+  };
+  let lints = lint_skills(&skills);
+  for lint in lints.iter() {
+    println!(
+      "{} ({}) {} {}: \"{}\" {}",
+      lint.skill_title, lint.skill_id, lint.location, lint.field, lint.rendered, lint.reason
+    );
+  }
+  println!("Checked {} skills, found {} fallbacks", skills.len(), lints.len());
+  return if lints.is_empty() { 0 } else { 1 };
+}
+
+fn runtime() -> tokio::runtime::Runtime {
+  return tokio::runtime::Builder::new_current_thread()
+    .enable_all()
+    .build()
+    .expect("Failed to start the tokio runtime");
+}
+
+pub fn run_integrity() -> i32 {
+  return runtime().block_on(print_integrity());
+}
+
+pub fn run_command(command: &str) -> Option<i32> {
+  return match command {
+    "check-integrity" => Some(run_integrity()),
+    "lint-content" => Some(runtime().block_on(print_content_lints())),
+    _ => None,
+  };
+}
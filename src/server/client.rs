@@ -1,66 +1,93 @@
 #[cfg(feature = "server")]
 use dioxus::prelude::ServerFnError;
 #[cfg(feature = "server")]
-use dioxus::prelude::*;
-#[cfg(feature = "server")]
-use futures::StreamExt;
-#[cfg(feature = "server")]
-use mongodb::bson::{from_document, Bson, Document};
-#[cfg(feature = "server")]
-use mongodb::{Client, Collection, Cursor};
+use mongodb::bson::{doc, oid::ObjectId, Bson, Deserializer, Document};
 #[cfg(feature = "server")]
 use serde::de::DeserializeOwned;
 #[cfg(feature = "server")]
 use std::collections::HashMap;
-#[cfg(feature = "server")]
-use std::marker::{Send, Sync};
-#[cfg(feature = "server")]
-use tokio::sync::OnceCell;
 
 #[cfg(feature = "server")]
-static MONGO_CLIENT: OnceCell<Client> = OnceCell::const_new();
+pub use super::storage::{storage, Storage};
 
 #[cfg(feature = "server")]
-pub async fn get_mongo_client() -> &'static Client {
-  MONGO_CLIENT
-    .get_or_init(|| async {
-      let uri = std::env::var("MONGO_URI").unwrap_or_else(|_| "mongodb://localhost:27017".into());
-
-      Client::with_uri_str(&uri)
-        .await
-        .expect("Failed to initialize MongoDB client")
-    })
-    .await
-}
+use super::diagnostics::LoadError;
 
 #[cfg(feature = "server")]
-pub async fn get_collection<T>(name: &str) -> Collection<T>
+fn parse_document<T>(collection: &str, document: Document) -> Result<(String, T), LoadError>
 where
-  T: DeserializeOwned + Unpin + Send + Sync,
+  T: DeserializeOwned,
 {
-  let client = get_mongo_client().await;
-  client.database("relic").collection::<T>(name)
+  let id = match document.get("_id") {
+    Some(Bson::ObjectId(oid)) => oid.to_hex(),
+    Some(other) => other.to_string(),
+    None => {
+      return Err(LoadError {
+        collection: collection.into(),
+        id: None,
+        path: "_id".into(),
+        message: "Document missing _id".into(),
+      });
+    },
+  };
+  let deserializer = Deserializer::new(Bson::Document(document));
+  return match serde_path_to_error::deserialize(deserializer) {
+    Ok(parsed) => Ok((id, parsed)),
+    Err(error) => Err(LoadError {
+      collection: collection.into(),
+      id: Some(id),
+      path: error.path().to_string(),
+      message: error.inner().to_string(),
+    }),
+  };
 }
 
 #[cfg(feature = "server")]
-pub async fn docs_to_map<T>(
-  mut cursor: Cursor<Document>,
-) -> Result<HashMap<String, T>, ServerFnError>
+pub fn docs_to_map<T>(
+  collection: &str, documents: Vec<Document>,
+) -> (HashMap<String, T>, Vec<LoadError>)
 where
   T: DeserializeOwned,
 {
   let mut map = HashMap::new();
-  while let Some(result) = cursor.next().await {
-    let doc = result.map_err(|e| ServerFnError::new(e.to_string()))?;
-    let id_bson = doc
-      .get("_id")
-      .ok_or_else(|| ServerFnError::new("Document missing _id"))?;
-    let id_str = match id_bson {
-      Bson::ObjectId(oid) => oid.to_hex(),
-      other => other.to_string(),
-    };
-    let parsed: T = from_document(doc).map_err(|e| ServerFnError::new(e.to_string()))?;
-    map.insert(id_str, parsed);
+  let mut errors = Vec::new();
+  for document in documents {
+    match parse_document(collection, document) {
+      Ok((id, parsed)) => {
+        map.insert(id, parsed);
+      },
+      Err(error) => {
+        tracing::warn!("Skipping document {}", error);
+        errors.push(error);
+      },
+    }
   }
-  Ok(map)
+  (map, errors)
+}
+
+#[cfg(feature = "server")]
+pub async fn load_map<T>(
+  collection: &str,
+) -> Result<(HashMap<String, T>, Vec<LoadError>), ServerFnError>
+where
+  T: DeserializeOwned,
+{
+  let documents = storage().await.find(collection, doc! {}).await?;
+  Ok(docs_to_map(collection, documents))
+}
+
+#[cfg(feature = "server")]
+pub fn id_filter(id: &str) -> Result<Document, ServerFnError> {
+  let object_id = ObjectId::parse_str(id).map_err(|e| ServerFnError::new(e.to_string()))?;
+  Ok(doc! { "_id": object_id })
+}
+
+#[cfg(feature = "server")]
+pub fn revision_filter(id: &str, revision: Option<i64>) -> Result<Document, ServerFnError> {
+  let mut filter = id_filter(id)?;
+  match revision {
+    Some(revision) => filter.insert("revision", revision),
+    None => filter.insert("revision", doc! { "$exists": false }),
+  };
+  Ok(filter)
 }
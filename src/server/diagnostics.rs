@@ -0,0 +1,44 @@
+use std::fmt;
+
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "server")]
+use super::client::load_map;
+#[cfg(feature = "server")]
+use crate::character::prelude::CharacterSheet;
+#[cfg(feature = "server")]
+use crate::equipment::{armor::Armor, weapon::Weapon};
+#[cfg(feature = "server")]
+use crate::keyword::prelude::Keyword;
+#[cfg(feature = "server")]
+use crate::path::prelude::Path;
+#[cfg(feature = "server")]
+use crate::skill::prelude::Skill;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LoadError {
+  pub collection: String,
+  pub id: Option<String>,
+  pub path: String,
+  pub message: String,
+}
+
+impl fmt::Display for LoadError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let id = self.id.clone().unwrap_or("without _id".into());
+    write!(f, "{} {} at {}: {}", self.collection, id, self.path, self.message)
+  }
+}
+
+#[server]
+pub async fn get_load_errors() -> Result<Vec<LoadError>, ServerFnError> {
+  let mut errors = Vec::new();
+  errors.extend(load_map::<Keyword>("keywords_display").await?.1);
+  errors.extend(load_map::<Skill>("skills_display").await?.1);
+  errors.extend(load_map::<Path>("paths_display").await?.1);
+  errors.extend(load_map::<Weapon>("weapons").await?.1);
+  errors.extend(load_map::<Armor>("armor").await?.1);
+  errors.extend(load_map::<CharacterSheet>("creatures").await?.1);
+  Ok(errors)
+}
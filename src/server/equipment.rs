@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use super::client::{id_filter, load_map, storage, Storage};
+#[cfg(feature = "server")]
+use bson::oid::ObjectId;
+#[cfg(feature = "server")]
+use mongodb::bson::to_document;
+
+use crate::equipment::armor::Armor;
+use crate::equipment::weapon::Weapon;
+
+#[cfg(feature = "server")]
+fn validate_weapon(weapon: &Weapon) -> Result<(), ServerFnError> {
+  if weapon.title.trim().is_empty() {
+    return Err(ServerFnError::new("Weapon title is required"));
+  }
+  if weapon.damage_dice.iter().any(|dice| dice.amount < 1) {
+    return Err(ServerFnError::new("Weapon damage dice must be positive"));
+  }
+  if let Some(error) = weapon.damage().limit_error() {
+    return Err(ServerFnError::new(format!("Weapon damage is invalid: {error}")));
+  }
+  Ok(())
+}
+
+#[cfg(feature = "server")]
+fn validate_armor(armor: &Armor) -> Result<(), ServerFnError> {
+  if armor.title.trim().is_empty() {
+    return Err(ServerFnError::new("Armor title is required"));
+  }
+  if armor.physical_resistance < 0 || armor.tenacity_requirement < 0 {
+    return Err(ServerFnError::new("Armor values cannot be negative"));
+  }
+  Ok(())
+}
+
+#[server]
+pub async fn get_weapon_map() -> Result<HashMap<String, Weapon>, ServerFnError> {
+  let (map, _) = load_map::<Weapon>("weapons").await?;
+  Ok(map)
+}
+
+#[server]
+pub async fn insert_weapon(weapon: Weapon) -> Result<String, ServerFnError> {
+  let mut weapon = weapon;
+  validate_weapon(&weapon)?;
+  weapon.id = ObjectId::new();
+  let document = to_document(&weapon).map_err(|e| ServerFnError::new(e.to_string()))?;
+  storage().await.insert("weapons", document).await?;
+  Ok(weapon.id.to_hex())
+}
+
+#[server]
+pub async fn update_weapon(weapon: Weapon) -> Result<String, ServerFnError> {
+  validate_weapon(&weapon)?;
+  let id = weapon.id.to_hex();
+  let document = to_document(&weapon).map_err(|e| ServerFnError::new(e.to_string()))?;
+  let replaced = storage().await.replace("weapons", id_filter(&id)?, document).await?;
+  if !replaced {
+    return Err(ServerFnError::new(format!("Weapon {id} does not exist")));
+  }
+  Ok(id)
+}
+
+#[server]
+pub async fn get_armor_map() -> Result<HashMap<String, Armor>, ServerFnError> {
+  let (map, _) = load_map::<Armor>("armor").await?;
+  Ok(map)
+}
+
+#[server]
+pub async fn insert_armor(armor: Armor) -> Result<String, ServerFnError> {
+  let mut armor = armor;
+  validate_armor(&armor)?;
+  armor.id = ObjectId::new();
+  let document = to_document(&armor).map_err(|e| ServerFnError::new(e.to_string()))?;
+  storage().await.insert("armor", document).await?;
+  Ok(armor.id.to_hex())
+}
+
+#[server]
+pub async fn update_armor(armor: Armor) -> Result<String, ServerFnError> {
+  validate_armor(&armor)?;
+  let id = armor.id.to_hex();
+  let document = to_document(&armor).map_err(|e| ServerFnError::new(e.to_string()))?;
+  let replaced = storage().await.replace("armor", id_filter(&id)?, document).await?;
+  if !replaced {
+    return Err(ServerFnError::new(format!("Armor {id} does not exist")));
+  }
+  Ok(id)
+}
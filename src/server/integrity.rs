@@ -0,0 +1,27 @@
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use super::equipment::{get_armor_map, get_weapon_map};
+#[cfg(feature = "server")]
+use super::keyword::get_keyword_map;
+#[cfg(feature = "server")]
+use super::path::get_path_map;
+#[cfg(feature = "server")]
+use super::sheet::get_character_sheet_map;
+#[cfg(feature = "server")]
+use super::skill::get_skill_map;
+#[cfg(feature = "server")]
+use crate::integrity::prelude::check_integrity;
+
+use crate::integrity::prelude::IntegrityReport;
+
+#[server]
+pub async fn check_compendium_integrity() -> Result<IntegrityReport, ServerFnError> {
+  let skills = get_skill_map().await?;
+  let paths = get_path_map().await?;
+  let keywords = get_keyword_map().await?;
+  let weapons = get_weapon_map().await?;
+  let armor = get_armor_map().await?;
+  let sheets = get_character_sheet_map().await?;
+  Ok(check_integrity(&skills, &paths, &keywords, &weapons, &armor, &sheets))
+}
@@ -3,19 +3,46 @@ use std::collections::HashMap;
 use dioxus::prelude::*;
 
 #[cfg(feature = "server")]
-use super::client::{docs_to_map, get_collection};
+use super::client::{id_filter, load_map, storage, Storage};
 #[cfg(feature = "server")]
-use mongodb::bson::{doc, Document};
+use bson::oid::ObjectId;
+#[cfg(feature = "server")]
+use mongodb::bson::to_document;
 
 use crate::keyword::prelude::*;
 
+#[cfg(feature = "server")]
+fn validate_keyword(keyword: &Keyword) -> Result<(), ServerFnError> {
+  if keyword.title.trim().is_empty() {
+    return Err(ServerFnError::new("Keyword title is required"));
+  }
+  Ok(())
+}
+
 #[server]
 pub async fn get_keyword_map() -> Result<HashMap<String, Keyword>, ServerFnError> {
-  let collection = get_collection::<Document>("keywords_display");
-  let cursor = collection.await.find(doc! {}).await.map_err(|e| {
-    tracing::error!("Unable to find collection {}", e);
-    ServerFnError::new(e.to_string())
-  })?;
-  let map = docs_to_map::<Keyword>(cursor).await?;
+  let (map, _) = load_map::<Keyword>("keywords_display").await?;
   Ok(map)
 }
+
+#[server]
+pub async fn insert_keyword(keyword: Keyword) -> Result<String, ServerFnError> {
+  let mut keyword = keyword;
+  validate_keyword(&keyword)?;
+  keyword.id = ObjectId::new();
+  let document = to_document(&keyword).map_err(|e| ServerFnError::new(e.to_string()))?;
+  storage().await.insert("keywords_display", document).await?;
+  Ok(keyword.id.to_hex())
+}
+
+#[server]
+pub async fn update_keyword(keyword: Keyword) -> Result<String, ServerFnError> {
+  validate_keyword(&keyword)?;
+  let id = keyword.id.to_hex();
+  let document = to_document(&keyword).map_err(|e| ServerFnError::new(e.to_string()))?;
+  let replaced = storage().await.replace("keywords_display", id_filter(&id)?, document).await?;
+  if !replaced {
+    return Err(ServerFnError::new(format!("Keyword {id} does not exist")));
+  }
+  Ok(id)
+}
@@ -0,0 +1,21 @@
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use super::path::get_path_map;
+#[cfg(feature = "server")]
+use super::sheet::get_character_sheet_map;
+#[cfg(feature = "server")]
+use super::skill::get_skill_map;
+#[cfg(feature = "server")]
+use crate::progression::prelude::legality_violations;
+
+#[server]
+pub async fn check_character_legality(id: String) -> Result<Vec<String>, ServerFnError> {
+  let sheets = get_character_sheet_map().await?;
+  let Some(sheet) = sheets.get(&id) else {
+    return Err(ServerFnError::new(format!("Character sheet {id} not found")));
+  };
+  let paths = get_path_map().await?;
+  let skills = get_skill_map().await?;
+  Ok(legality_violations(sheet, &paths, &skills))
+}
@@ -1,12 +1,38 @@
+#[cfg(feature = "server")]
+pub mod cli;
 mod client;
+mod diagnostics;
+mod equipment;
+mod integrity;
 mod keyword;
+mod legality;
+mod pack;
 mod path;
+mod play;
+mod rolls;
 mod sheet;
 mod signal;
 mod skill;
+#[cfg(feature = "server")]
+mod storage;
 
 pub mod prelude {
+  pub use super::diagnostics::{get_load_errors, LoadError};
+  pub use super::equipment::{insert_armor, insert_weapon, update_armor, update_weapon};
+  pub use super::integrity::check_compendium_integrity;
+  pub use super::keyword::{insert_keyword, update_keyword};
+  pub use super::legality::check_character_legality;
+  pub use super::pack::{
+    export_content_pack, import_content_pack, ConflictMode, IdMode,
+    ImportOptions, ImportReport, CONTENT_PACK_VERSION,
+  };
+  pub use super::path::{insert_path, update_path};
+  pub use super::play::{get_play_state, list_play_sessions, save_play_state};
+  pub use super::rolls::{get_roll_log, roll_at_table, stream_roll_log};
+  pub use super::sheet::{delete_character_sheet, insert_character_sheet, update_character_sheet};
+  pub use super::skill::{insert_skill, update_skill};
   pub use super::signal::{
-    status_element_paths_skills_keywords, KeywordCache, PathCache, SkillCache, CharacterSheetCache,
+    status_element_paths_skills_keywords, ArmorCache, CharacterSheetCache, KeywordCache, PathCache,
+    ResourceStatus, SkillCache, WeaponCache, sheet_with_catalog,
   };
 }
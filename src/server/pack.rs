@@ -0,0 +1,419 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use super::client::{storage, Storage};
+#[cfg(feature = "server")]
+use mongodb::bson::{doc, oid::ObjectId, Bson, Document};
+#[cfg(feature = "server")]
+use std::collections::{BTreeMap, HashMap};
+
+pub const CONTENT_PACK_VERSION: u32 = 2;
+
+#[cfg(feature = "server")]
+const CONTENT_PACK_FORMAT: &str = "relic-content-pack";
+#[cfg(feature = "server")]
+const CONTENT_COLLECTIONS: [&str; 5] =
+  ["keywords_display", "skills_display", "paths_display", "weapons", "armor"];
+#[cfg(feature = "server")]
+const CREATURE_COLLECTION: &str = "creatures";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub enum IdMode {
+  #[default]
+  Keep,
+  Remap,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub enum ConflictMode {
+  #[default]
+  Skip,
+  Overwrite,
+}
+
+impl IdMode {
+  pub fn ordered() -> Vec<IdMode> {
+    return vec![IdMode::Keep, IdMode::Remap];
+  }
+}
+
+impl fmt::Display for IdMode {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        IdMode::Keep => "Keep ids",
+        IdMode::Remap => "Assign new ids",
+      }
+    )
+  }
+}
+
+impl ConflictMode {
+  pub fn ordered() -> Vec<ConflictMode> {
+    return vec![ConflictMode::Skip, ConflictMode::Overwrite];
+  }
+}
+
+impl fmt::Display for ConflictMode {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        ConflictMode::Skip => "Keep existing",
+        ConflictMode::Overwrite => "Overwrite existing",
+      }
+    )
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct ImportOptions {
+  pub id_mode: IdMode,
+  pub conflict_mode: ConflictMode,
+  pub include_creatures: bool,
+  pub dry_run: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ImportConflict {
+  pub collection: String,
+  pub id: String,
+  pub title: String,
+  pub resolution: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct CollectionReport {
+  pub collection: String,
+  pub inserted: usize,
+  pub replaced: usize,
+  pub skipped: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct ImportReport {
+  pub version: u32,
+  pub dry_run: bool,
+  pub collections: Vec<CollectionReport>,
+  pub conflicts: Vec<ImportConflict>,
+}
+
+#[cfg(feature = "server")]
+#[derive(Serialize, Deserialize)]
+struct ContentPack {
+  format: String,
+  version: u32,
+  collections: BTreeMap<String, Vec<serde_json::Value>>,
+}
+
+#[cfg(feature = "server")]
+fn pack_error(error: impl std::fmt::Display) -> ServerFnError {
+  return ServerFnError::new(format!("Invalid content pack: {error}"));
+}
+
+#[cfg(feature = "server")]
+fn document_title(document: &Document) -> String {
+  return document
+    .get_str("title")
+    .or_else(|_| document.get_str("name"))
+    .unwrap_or_default()
+    .into();
+}
+
+#[cfg(feature = "server")]
+fn remap_ids(value: &mut Bson, ids: &HashMap<ObjectId, ObjectId>) {
+  match value {
+    Bson::ObjectId(id) => {
+      if let Some(new_id) = ids.get(id) {
+        *id = *new_id;
+      }
+    },
+    Bson::Document(document) => {
+      for (_, value) in document.iter_mut() {
+        remap_ids(value, ids);
+      }
+    },
+    Bson::Array(values) => {
+      for value in values.iter_mut() {
+        remap_ids(value, ids);
+      }
+    },
+    _ => (),
+  }
+}
+
+#[cfg(feature = "server")]
+fn pack_documents(
+  pack: ContentPack, include_creatures: bool,
+) -> Result<Vec<(String, Vec<Document>)>, ServerFnError> {
+  if pack.format != CONTENT_PACK_FORMAT {
+    return Err(pack_error(format!("unknown format {}", pack.format)));
+  }
+  if pack.version > CONTENT_PACK_VERSION {
+    return Err(pack_error(format!(
+      "version {} is newer than supported version {CONTENT_PACK_VERSION}",
+      pack.version
+    )));
+  }
+  let mut collections = pack.collections;
+  let mut documents = Vec::new();
+  for collection in CONTENT_COLLECTIONS.into_iter().chain([CREATURE_COLLECTION]) {
+    let values = collections.remove(collection).unwrap_or_default();
+    if collection == CREATURE_COLLECTION && !include_creatures {
+      continue;
+    }
+    let mut parsed = Vec::new();
+    for value in values {
+      match Bson::try_from(value).map_err(pack_error)? {
+        Bson::Document(document) => parsed.push(document),
+        other => return Err(pack_error(format!("{collection} contains {other}, expected a document"))),
+      }
+    }
+    documents.push((collection.to_string(), parsed));
+  }
+  if let Some(collection) = collections.keys().next() {
+    return Err(pack_error(format!("unknown collection {collection}")));
+  }
+  Ok(documents)
+}
+
+#[server]
+pub async fn export_content_pack(include_creatures: bool) -> Result<String, ServerFnError> {
+  let mut collections = BTreeMap::new();
+  let mut names = CONTENT_COLLECTIONS.to_vec();
+  if include_creatures {
+    names.push(CREATURE_COLLECTION);
+  }
+  for collection in names {
+    let documents = storage().await.find(collection, doc! {}).await?;
+    let values = documents
+      .into_iter()
+      .map(|document| Bson::Document(document).into_relaxed_extjson())
+      .collect();
+    collections.insert(collection.to_string(), values);
+  }
+  let pack = ContentPack {
+    format: CONTENT_PACK_FORMAT.into(),
+    version: CONTENT_PACK_VERSION,
+    collections,
+  };
+  serde_json::to_string_pretty(&pack).map_err(|e| ServerFnError::new(e.to_string()))
+}
+
+#[cfg(feature = "server")]
+fn remap_pack_ids(collections: &mut [(String, Vec<Document>)]) {
+  let mut ids = HashMap::new();
+  for (_, documents) in collections.iter() {
+    for document in documents {
+      if let Ok(id) = document.get_object_id("_id") {
+        ids.insert(id, ObjectId::new());
+      }
+    }
+  }
+  for (_, documents) in collections.iter_mut() {
+    for document in documents.iter_mut() {
+      let mut value = Bson::Document(std::mem::take(document));
+      remap_ids(&mut value, &ids);
+      if let Bson::Document(remapped) = value {
+        *document = remapped;
+      }
+    }
+  }
+}
+
+#[server]
+pub async fn import_content_pack(
+  pack: String, options: ImportOptions,
+) -> Result<ImportReport, ServerFnError> {
+  let pack: ContentPack = serde_json::from_str(&pack).map_err(pack_error)?;
+  import_pack(storage().await, pack, options).await
+}
+
+#[cfg(feature = "server")]
+async fn import_pack(
+  store: &impl Storage, pack: ContentPack, options: ImportOptions,
+) -> Result<ImportReport, ServerFnError> {
+  let version = pack.version;
+  let mut collections = pack_documents(pack, options.include_creatures)?;
+  if options.id_mode == IdMode::Remap {
+    remap_pack_ids(&mut collections);
+  }
+  let mut report = ImportReport { version, dry_run: options.dry_run, ..Default::default() };
+  for (collection, documents) in collections {
+    let existing: HashMap<ObjectId, String> = store
+      .find(&collection, doc! {})
+      .await?
+      .iter()
+      .filter_map(|document| {
+        let id = document.get_object_id("_id").ok()?;
+        Some((id, document_title(document)))
+      })
+      .collect();
+    let mut collection_report = CollectionReport { collection: collection.clone(), ..Default::default() };
+    for document in documents {
+      let id = document
+        .get_object_id("_id")
+        .map_err(|_| pack_error(format!("{collection} document is missing an ObjectId _id")))?;
+      let title = document_title(&document);
+      let Some(existing_title) = existing.get(&id) else {
+        if existing.values().any(|existing_title| !title.is_empty() && existing_title == &title) {
+          report.conflicts.push(ImportConflict {
+            collection: collection.clone(),
+            id: id.to_hex(),
+            title: title.clone(),
+            resolution: "Imported alongside an existing document with the same title".into(),
+          });
+        }
+        if !options.dry_run {
+          store.insert(&collection, document).await?;
+        }
+        collection_report.inserted += 1;
+        continue;
+      };
+      let resolution = match options.conflict_mode {
+        ConflictMode::Skip => {
+          collection_report.skipped += 1;
+          format!("Kept existing {existing_title}")
+        },
+        ConflictMode::Overwrite => {
+          if !options.dry_run {
+            store.replace(&collection, doc! { "_id": id }, document).await?;
+          }
+          collection_report.replaced += 1;
+          format!("Replaced existing {existing_title}")
+        },
+      };
+      report.conflicts.push(ImportConflict { collection: collection.clone(), id: id.to_hex(), title, resolution });
+    }
+    report.collections.push(collection_report);
+  }
+  Ok(report)
+}
+
+#[cfg(all(test, feature = "server"))]
+mod tests {
+  use super::*;
+  use crate::server::storage::LocalStorage;
+
+  fn run<T>(future: impl std::future::Future<Output = T>) -> T {
+    return tokio::runtime::Builder::new_current_thread()
+      .enable_all()
+      .build()
+      .expect("Failed to start the tokio runtime")
+      .block_on(future);
+  }
+
+  fn oid(id: &ObjectId) -> serde_json::Value {
+    return serde_json::json!({ "$oid": id.to_hex() });
+  }
+
+  fn pack(keyword: &ObjectId, skill: &ObjectId) -> ContentPack {
+    let pack = serde_json::json!({
+      "format": CONTENT_PACK_FORMAT,
+      "version": CONTENT_PACK_VERSION,
+      "collections": {
+        "keywords_display": [{ "_id": oid(keyword), "title": "Burning" }],
+        "skills_display": [{
+          "_id": oid(skill),
+          "title": "Firebrand",
+          "action": { "keywordIds": [oid(keyword)] },
+        }],
+      },
+    });
+    return serde_json::from_value(pack).unwrap();
+  }
+
+  fn options(id_mode: IdMode, conflict_mode: ConflictMode, dry_run: bool) -> ImportOptions {
+    return ImportOptions { id_mode, conflict_mode, include_creatures: false, dry_run };
+  }
+
+  async fn only(store: &LocalStorage, collection: &str) -> Document {
+    let documents = store.find(collection, doc! {}).await.unwrap();
+    assert_eq!(documents.len(), 1);
+    return documents[0].clone();
+  }
+
+  fn skill_keyword(skill: &Document) -> ObjectId {
+    let action = skill.get_document("action").unwrap();
+    return action.get_array("keywordIds").unwrap()[0].as_object_id().unwrap();
+  }
+
+  #[test]
+  fn keeps_ids_and_references() {
+    run(async {
+      let store = LocalStorage::new(None);
+      let (keyword, skill) = (ObjectId::new(), ObjectId::new());
+      let options = options(IdMode::Keep, ConflictMode::Skip, false);
+      let report = import_pack(&store, pack(&keyword, &skill), options).await.unwrap();
+      assert!(report.conflicts.is_empty());
+      assert_eq!(only(&store, "keywords_display").await.get_object_id("_id"), Ok(keyword));
+      let imported = only(&store, "skills_display").await;
+      assert_eq!(imported.get_object_id("_id"), Ok(skill));
+      assert_eq!(skill_keyword(&imported), keyword);
+    });
+  }
+
+  #[test]
+  fn remaps_ids_and_references_together() {
+    run(async {
+      let store = LocalStorage::new(None);
+      let (keyword, skill) = (ObjectId::new(), ObjectId::new());
+      let options = options(IdMode::Remap, ConflictMode::Skip, false);
+      import_pack(&store, pack(&keyword, &skill), options).await.unwrap();
+      let new_keyword = only(&store, "keywords_display").await.get_object_id("_id").unwrap();
+      let imported = only(&store, "skills_display").await;
+      assert_ne!(new_keyword, keyword);
+      assert_ne!(imported.get_object_id("_id").unwrap(), skill);
+      assert_eq!(skill_keyword(&imported), new_keyword);
+    });
+  }
+
+  #[test]
+  fn reports_id_and_title_conflicts() {
+    run(async {
+      let store = LocalStorage::new(None);
+      let (keyword, skill) = (ObjectId::new(), ObjectId::new());
+      store.insert("keywords_display", doc! { "_id": keyword, "title": "Ablaze" }).await.unwrap();
+      let existing_skill = doc! { "_id": ObjectId::new(), "title": "Firebrand" };
+      store.insert("skills_display", existing_skill).await.unwrap();
+      let skip = options(IdMode::Keep, ConflictMode::Skip, false);
+      let report = import_pack(&store, pack(&keyword, &skill), skip).await.unwrap();
+      let resolutions: Vec<&str> =
+        report.conflicts.iter().map(|conflict| conflict.resolution.as_str()).collect();
+      assert_eq!(
+        resolutions,
+        vec!["Kept existing Ablaze", "Imported alongside an existing document with the same title"]
+      );
+      assert_eq!(only(&store, "keywords_display").await.get_str("title"), Ok("Ablaze"));
+      assert_eq!(store.find("skills_display", doc! {}).await.unwrap().len(), 2);
+
+      let overwrite = options(IdMode::Keep, ConflictMode::Overwrite, false);
+      let report = import_pack(&store, pack(&keyword, &skill), overwrite).await.unwrap();
+      assert_eq!(report.collections[0].replaced, 1);
+      assert_eq!(only(&store, "keywords_display").await.get_str("title"), Ok("Burning"));
+    });
+  }
+
+  #[test]
+  fn dry_run_reports_without_writing() {
+    run(async {
+      let store = LocalStorage::new(None);
+      let keyword = ObjectId::new();
+      store.insert("keywords_display", doc! { "_id": keyword, "title": "Ablaze" }).await.unwrap();
+      let options = options(IdMode::Keep, ConflictMode::Overwrite, true);
+      let report = import_pack(&store, pack(&keyword, &ObjectId::new()), options).await.unwrap();
+      assert!(report.dry_run);
+      assert_eq!(report.collections[0].replaced, 1);
+      assert_eq!(report.collections[1].inserted, 1);
+      assert_eq!(only(&store, "keywords_display").await.get_str("title"), Ok("Ablaze"));
+      assert!(store.find("skills_display", doc! {}).await.unwrap().is_empty());
+    });
+  }
+}
@@ -3,19 +3,60 @@ use std::collections::HashMap;
 use dioxus::prelude::*;
 
 #[cfg(feature = "server")]
-use super::client::{docs_to_map, get_collection};
+use super::client::{id_filter, load_map, storage, Storage};
 #[cfg(feature = "server")]
-use mongodb::bson::{doc, Document};
+use bson::oid::ObjectId;
+#[cfg(feature = "server")]
+use mongodb::bson::to_document;
 
 use crate::path::prelude::*;
+#[cfg(feature = "server")]
+use crate::skill::prelude::Skill;
+
+#[cfg(feature = "server")]
+fn validate_path(path: &Path) -> Result<(), ServerFnError> {
+  if path.title.trim().is_empty() {
+    return Err(ServerFnError::new("Path title is required"));
+  }
+  Ok(())
+}
+
+#[cfg(feature = "server")]
+async fn relink_skills(path: &Path) -> Result<(), ServerFnError> {
+  let (skills, _) = load_map::<Skill>("skills_display").await?;
+  for skill in path.relinked_skills(&skills) {
+    let document = to_document(&skill).map_err(|e| ServerFnError::new(e.to_string()))?;
+    storage().await.replace("skills_display", id_filter(&skill.id.to_hex())?, document).await?;
+  }
+  Ok(())
+}
 
 #[server]
 pub async fn get_path_map() -> Result<HashMap<String, Path>, ServerFnError> {
-  let collection = get_collection::<Document>("paths_display");
-  let cursor = collection.await.find(doc! {}).await.map_err(|e| {
-    tracing::error!("Unable to find collection {}", e);
-    ServerFnError::new(e.to_string())
-  })?;
-  let map = docs_to_map::<Path>(cursor).await?;
+  let (map, _) = load_map::<Path>("paths_display").await?;
   Ok(map)
 }
+
+#[server]
+pub async fn insert_path(path: Path) -> Result<String, ServerFnError> {
+  let mut path = path;
+  validate_path(&path)?;
+  path.id = ObjectId::new();
+  let document = to_document(&path).map_err(|e| ServerFnError::new(e.to_string()))?;
+  storage().await.insert("paths_display", document).await?;
+  relink_skills(&path).await?;
+  Ok(path.id.to_hex())
+}
+
+#[server]
+pub async fn update_path(path: Path) -> Result<String, ServerFnError> {
+  validate_path(&path)?;
+  let id = path.id.to_hex();
+  let document = to_document(&path).map_err(|e| ServerFnError::new(e.to_string()))?;
+  let replaced = storage().await.replace("paths_display", id_filter(&id)?, document).await?;
+  if !replaced {
+    return Err(ServerFnError::new(format!("Path {id} does not exist")));
+  }
+  relink_skills(&path).await?;
+  Ok(id)
+}
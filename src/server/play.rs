@@ -0,0 +1,81 @@
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use super::client::{docs_to_map, revision_filter, storage, Storage};
+#[cfg(feature = "server")]
+use bson::oid::ObjectId;
+#[cfg(feature = "server")]
+use mongodb::bson::{doc, oid, to_document, Document};
+
+use crate::play::prelude::PlayState;
+
+#[cfg(feature = "server")]
+const PLAY_SESSIONS: &str = "play_sessions";
+
+#[cfg(feature = "server")]
+fn character_filter(character_id: &str) -> Result<Document, ServerFnError> {
+  let object_id =
+    oid::ObjectId::parse_str(character_id).map_err(|e| ServerFnError::new(e.to_string()))?;
+  Ok(doc! { "characterId": object_id })
+}
+
+#[cfg(feature = "server")]
+async fn find_play_states(filter: Document) -> Result<Vec<PlayState>, ServerFnError> {
+  let documents = storage().await.find(PLAY_SESSIONS, filter).await?;
+  let (map, _) = docs_to_map::<PlayState>(PLAY_SESSIONS, documents);
+  Ok(map.into_values().collect())
+}
+
+#[server]
+pub async fn list_play_sessions(character_id: String) -> Result<Vec<String>, ServerFnError> {
+  let filter = character_filter(&character_id)?;
+  let mut sessions: Vec<String> =
+    find_play_states(filter).await?.into_iter().map(|state| state.session).collect();
+  sessions.sort();
+  Ok(sessions)
+}
+
+#[server]
+pub async fn get_play_state(
+  character_id: String, session: String,
+) -> Result<PlayState, ServerFnError> {
+  let mut filter = character_filter(&character_id)?;
+  filter.insert("session", session.clone());
+  let existing = find_play_states(filter).await?.into_iter().next();
+  let character_id =
+    ObjectId::parse_str(&character_id).map_err(|e| ServerFnError::new(e.to_string()))?;
+  Ok(existing.unwrap_or_else(|| PlayState::new(character_id, session)))
+}
+
+#[server]
+pub async fn save_play_state(state: PlayState) -> Result<PlayState, ServerFnError> {
+  let mut state = state;
+  let errors = state.validation_errors();
+  if !errors.is_empty() {
+    return Err(ServerFnError::new(format!("Invalid play state: {}", errors.join(", "))));
+  }
+  let id = state.id.to_hex();
+  let previous = state.revision;
+  state.revision = Some(previous.unwrap_or_default() + 1);
+  let document = to_document(&state).map_err(|e| ServerFnError::new(e.to_string()))?;
+  if previous.is_none() {
+    let mut filter = character_filter(&state.character_id.to_hex())?;
+    filter.insert("session", state.session.clone());
+    if !storage().await.insert_unique(PLAY_SESSIONS, filter, document).await? {
+      return Err(ServerFnError::new(format!(
+        "Session {} already exists for this character, reload it and try again",
+        state.session
+      )));
+    }
+    return Ok(state);
+  }
+  let filter = revision_filter(&id, previous)?;
+  let replaced = storage().await.replace(PLAY_SESSIONS, filter, document).await?;
+  if !replaced {
+    return Err(ServerFnError::new(format!(
+      "Session {} was changed by someone else, reload it and try again",
+      state.session
+    )));
+  }
+  Ok(state)
+}
@@ -0,0 +1,104 @@
+use dioxus::fullstack::JsonStream;
+use dioxus::prelude::*;
+
+#[cfg(feature = "server")]
+use super::client::{docs_to_map, storage, Storage};
+#[cfg(feature = "server")]
+use super::equipment::{get_armor_map, get_weapon_map};
+#[cfg(feature = "server")]
+use super::play::get_play_state;
+#[cfg(feature = "server")]
+use super::sheet::get_character_sheet_map;
+#[cfg(feature = "server")]
+use super::skill::get_skill_map;
+#[cfg(feature = "server")]
+use crate::skill::prelude::Skill;
+#[cfg(feature = "server")]
+use mongodb::bson::{doc, to_document};
+#[cfg(feature = "server")]
+use std::sync::OnceLock;
+#[cfg(feature = "server")]
+use tokio::sync::broadcast;
+
+use crate::play::prelude::{RollLogEntry, RollRequest};
+
+#[cfg(feature = "server")]
+const ROLL_LOG: &str = "roll_log";
+#[cfg(feature = "server")]
+const ROLL_LOG_LIMIT: usize = 100;
+#[cfg(feature = "server")]
+const ROLL_FEED_CAPACITY: usize = 64;
+
+#[cfg(feature = "server")]
+static ROLL_FEED: OnceLock<broadcast::Sender<RollLogEntry>> = OnceLock::new();
+
+#[cfg(feature = "server")]
+fn roll_feed() -> &'static broadcast::Sender<RollLogEntry> {
+  return ROLL_FEED.get_or_init(|| broadcast::channel(ROLL_FEED_CAPACITY).0);
+}
+
+#[server]
+pub async fn roll_at_table(request: RollRequest) -> Result<RollLogEntry, ServerFnError> {
+  let errors = request.validation_errors();
+  if !errors.is_empty() {
+    return Err(ServerFnError::new(format!("Invalid roll: {}", errors.join(", "))));
+  }
+  let id = request.character_id.to_hex();
+  let sheets = get_character_sheet_map().await?;
+  let Some(sheet) = sheets.get(&id) else {
+    return Err(ServerFnError::new(format!("Character sheet {id} not found")));
+  };
+  let state = get_play_state(id, request.session.clone()).await?;
+  let Some(campaign) = state.campaign.clone().filter(|campaign| !campaign.trim().is_empty()) else {
+    return Err(ServerFnError::new("Join a campaign and save the session to roll at the table"));
+  };
+  let skill_map = get_skill_map().await?;
+  let skills: Vec<Skill> =
+    sheet.skills.iter().filter_map(|id| skill_map.get(&id.to_hex())).cloned().collect();
+  let weapons = get_weapon_map().await?;
+  let armor = get_armor_map().await?;
+  let sheet = sheet.with_catalog(
+    |id| armor.get(&id.to_hex()).cloned(),
+    |id| weapons.get(&id.to_hex()).cloned(),
+  );
+  let resolved = request
+    .resolve(campaign.trim().to_string(), &sheet, &state, &skills)
+    .map_err(ServerFnError::new)?;
+  let errors = resolved.validation_errors();
+  if !errors.is_empty() {
+    return Err(ServerFnError::new(format!("Invalid roll: {}", errors.join(", "))));
+  }
+  let entry = RollLogEntry::resolve(resolved, &mut rand::rng());
+  let document = to_document(&entry).map_err(|e| ServerFnError::new(e.to_string()))?;
+  storage().await.insert(ROLL_LOG, document).await?;
+  let _ = roll_feed().send(entry.clone());
+  Ok(entry)
+}
+
+#[server]
+pub async fn get_roll_log(campaign: String) -> Result<Vec<RollLogEntry>, ServerFnError> {
+  let filter = doc! { "campaign": campaign };
+  let documents = storage().await.find_latest(ROLL_LOG, filter, ROLL_LOG_LIMIT).await?;
+  let (map, _) = docs_to_map::<RollLogEntry>(ROLL_LOG, documents);
+  let mut entries: Vec<RollLogEntry> = map.into_values().collect();
+  entries.sort_by_key(|entry| entry.id);
+  Ok(entries)
+}
+
+#[server]
+pub async fn stream_roll_log(campaign: String) -> Result<JsonStream<RollLogEntry>, ServerFnError> {
+  let receiver = roll_feed().subscribe();
+  let entries = futures::stream::unfold(receiver, move |mut receiver| {
+    let campaign = campaign.clone();
+    async move {
+      loop {
+        match receiver.recv().await {
+          Ok(entry) if entry.campaign == campaign => return Some((entry, receiver)),
+          Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+          Err(broadcast::error::RecvError::Closed) => return None,
+        }
+      }
+    }
+  });
+  Ok(JsonStream::new(entries))
+}
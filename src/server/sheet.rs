@@ -3,19 +3,73 @@ use std::collections::HashMap;
 use dioxus::prelude::*;
 
 #[cfg(feature = "server")]
-use super::client::{docs_to_map, get_collection};
+use super::client::{load_map, revision_filter, storage, Storage};
 #[cfg(feature = "server")]
-use mongodb::bson::{doc, Document};
+use bson::oid::ObjectId;
+#[cfg(feature = "server")]
+use mongodb::bson::to_document;
 
 use crate::character::prelude::CharacterSheet;
+#[cfg(feature = "server")]
+use crate::equipment::{armor::Armor, weapon::Weapon};
+
+#[cfg(feature = "server")]
+async fn validate_sheet(sheet: &CharacterSheet) -> Result<(), ServerFnError> {
+  let (armor, _) = load_map::<Armor>("armor").await?;
+  let (weapons, _) = load_map::<Weapon>("weapons").await?;
+  let resolved = sheet.with_catalog(
+    |id| armor.get(&id.to_string()).cloned(),
+    |id| weapons.get(&id.to_string()).cloned(),
+  );
+  let errors = resolved.validation_errors();
+  if errors.is_empty() {
+    return Ok(());
+  }
+  Err(ServerFnError::new(format!("Invalid character sheet: {}", errors.join(", "))))
+}
 
 #[server]
 pub async fn get_character_sheet_map() -> Result<HashMap<String, CharacterSheet>, ServerFnError> {
-  let collection = get_collection::<Document>("creatures");
-  let cursor = collection.await.find(doc! {}).await.map_err(|e| {
-    tracing::error!("Unable to find collection {}", e);
-    ServerFnError::new(e.to_string())
-  })?;
-  let map = docs_to_map::<CharacterSheet>(cursor).await?;
+  let (map, _) = load_map::<CharacterSheet>("creatures").await?;
   Ok(map)
 }
+
+#[server]
+pub async fn insert_character_sheet(sheet: CharacterSheet) -> Result<String, ServerFnError> {
+  let mut sheet = sheet;
+  validate_sheet(&sheet).await?;
+  sheet.id = ObjectId::new();
+  sheet.revision = Some(1);
+  let document = to_document(&sheet).map_err(|e| ServerFnError::new(e.to_string()))?;
+  storage().await.insert("creatures", document).await?;
+  Ok(sheet.id.to_hex())
+}
+
+#[server]
+pub async fn update_character_sheet(sheet: CharacterSheet) -> Result<CharacterSheet, ServerFnError> {
+  let mut sheet = sheet;
+  validate_sheet(&sheet).await?;
+  let id = sheet.id.to_hex();
+  let filter = revision_filter(&id, sheet.revision)?;
+  sheet.revision = Some(sheet.revision.unwrap_or_default() + 1);
+  let document = to_document(&sheet).map_err(|e| ServerFnError::new(e.to_string()))?;
+  let replaced = storage().await.replace("creatures", filter, document).await?;
+  if !replaced {
+    return Err(ServerFnError::new(format!(
+      "Character sheet {id} was changed or removed by someone else, reload it and try again"
+    )));
+  }
+  Ok(sheet)
+}
+
+#[server]
+pub async fn delete_character_sheet(id: String, revision: Option<i64>) -> Result<(), ServerFnError> {
+  let filter = revision_filter(&id, revision)?;
+  let deleted = storage().await.delete("creatures", filter).await?;
+  if !deleted {
+    return Err(ServerFnError::new(format!(
+      "Character sheet {id} was changed or removed by someone else, reload it and try again"
+    )));
+  }
+  Ok(())
+}
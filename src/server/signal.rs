@@ -7,7 +7,9 @@ use crate::keyword::prelude::*;
 use crate::path::prelude::*;
 use crate::skill::prelude::*;
 use crate::character::prelude::*;
+use crate::equipment::{armor::Armor, weapon::Weapon};
 
+use super::equipment::{get_armor_map, get_weapon_map};
 use super::keyword::get_keyword_map;
 use super::path::get_path_map;
 use super::skill::get_skill_map;
@@ -56,6 +58,11 @@ where
     }
   }
 
+  pub fn restart(&self) {
+    let mut resource = self.resource;
+    resource.restart();
+  }
+
   pub fn status_element(&self) -> Option<Element> {
     return elements_by_status(&self.status(), "");
   }
@@ -129,6 +136,35 @@ impl CharacterSheetCache {
   }
 }
 
+#[derive(Debug, PartialEq, Clone)]
+pub struct WeaponCache(pub MapCache<Weapon>);
+
+impl WeaponCache {
+  pub fn use_context_provider() -> Self {
+    let resource = use_resource(get_weapon_map);
+    use_context_provider(|| WeaponCache(MapCache { resource }))
+  }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ArmorCache(pub MapCache<Armor>);
+
+impl ArmorCache {
+  pub fn use_context_provider() -> Self {
+    let resource = use_resource(get_armor_map);
+    use_context_provider(|| ArmorCache(MapCache { resource }))
+  }
+}
+
+pub fn sheet_with_catalog(sheet: &CharacterSheet) -> CharacterSheet {
+  let ArmorCache(ref armor_cache) = use_context();
+  let WeaponCache(ref weapon_cache) = use_context();
+  return sheet.with_catalog(
+    |id| armor_cache.from_object_id(id),
+    |id| weapon_cache.from_object_id(id),
+  );
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct PathCache(pub MapCache<Path>);
 
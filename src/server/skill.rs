@@ -3,19 +3,46 @@ use std::collections::HashMap;
 use dioxus::prelude::*;
 
 #[cfg(feature = "server")]
-use super::client::{docs_to_map, get_collection};
+use super::client::{id_filter, load_map, storage, Storage};
 #[cfg(feature = "server")]
-use mongodb::bson::{doc, Document};
+use bson::oid::ObjectId;
+#[cfg(feature = "server")]
+use mongodb::bson::to_document;
 
 use crate::skill::Skill;
 
+#[cfg(feature = "server")]
+fn validate_skill(skill: &Skill) -> Result<(), ServerFnError> {
+  if skill.title.trim().is_empty() {
+    return Err(ServerFnError::new("Skill title is required"));
+  }
+  Ok(())
+}
+
 #[server]
 pub async fn get_skill_map() -> Result<HashMap<String, Skill>, ServerFnError> {
-  let collection = get_collection::<Document>("skills_display");
-  let cursor = collection.await.find(doc! {}).await.map_err(|e| {
-    tracing::error!("Unable to find collection {}", e);
-    ServerFnError::new(e.to_string())
-  })?;
-  let map = docs_to_map::<Skill>(cursor).await?;
+  let (map, _) = load_map::<Skill>("skills_display").await?;
   Ok(map)
 }
+
+#[server]
+pub async fn insert_skill(skill: Skill) -> Result<String, ServerFnError> {
+  let mut skill = skill;
+  validate_skill(&skill)?;
+  skill.id = ObjectId::new();
+  let document = to_document(&skill).map_err(|e| ServerFnError::new(e.to_string()))?;
+  storage().await.insert("skills_display", document).await?;
+  Ok(skill.id.to_hex())
+}
+
+#[server]
+pub async fn update_skill(skill: Skill) -> Result<String, ServerFnError> {
+  validate_skill(&skill)?;
+  let id = skill.id.to_hex();
+  let document = to_document(&skill).map_err(|e| ServerFnError::new(e.to_string()))?;
+  let replaced = storage().await.replace("skills_display", id_filter(&id)?, document).await?;
+  if !replaced {
+    return Err(ServerFnError::new(format!("Skill {id} does not exist")));
+  }
+  Ok(id)
+}
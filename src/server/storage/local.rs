@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use dioxus::prelude::ServerFnError;
+use mongodb::bson::{Bson, Document};
+use tokio::sync::Mutex;
+
+use super::Storage;
+
+pub struct LocalStorage {
+  directory: Option<PathBuf>,
+  collections: Mutex<HashMap<String, Vec<Document>>>,
+}
+
+impl LocalStorage {
+  pub fn new(directory: Option<PathBuf>) -> Self {
+    return LocalStorage { directory, collections: Mutex::new(HashMap::new()) };
+  }
+
+  fn collection_file(&self, collection: &str) -> Option<PathBuf> {
+    return self
+      .directory
+      .as_ref()
+      .map(|directory| directory.join(format!("{collection}.json")));
+  }
+
+  async fn load(&self, collection: &str) -> Result<Vec<Document>, ServerFnError> {
+    let Some(file) = self.collection_file(collection) else {
+      return Ok(Vec::new());
+    };
+    if !tokio::fs::try_exists(&file).await.map_err(|e| file_error("read", &file, e))? {
+      return Ok(Vec::new());
+    }
+    let text = tokio::fs::read_to_string(&file).await.map_err(|e| file_error("read", &file, e))?;
+    let values: Vec<serde_json::Value> =
+      serde_json::from_str(&text).map_err(|e| file_error("parse", &file, e))?;
+    let mut documents = Vec::new();
+    for value in values {
+      match Bson::try_from(value).map_err(|e| file_error("parse", &file, e))? {
+        Bson::Document(document) => documents.push(document),
+        other => return Err(file_error("parse", &file, format!("expected a document, found {other}"))),
+      }
+    }
+    Ok(documents)
+  }
+
+  async fn save(&self, collection: &str, documents: &[Document]) -> Result<(), ServerFnError> {
+    let Some(file) = self.collection_file(collection) else {
+      return Ok(());
+    };
+    if let Some(directory) = file.parent() {
+      tokio::fs::create_dir_all(directory).await.map_err(|e| file_error("create", directory, e))?;
+    }
+    let values: Vec<serde_json::Value> = documents
+      .iter()
+      .map(|document| Bson::Document(document.clone()).into_relaxed_extjson())
+      .collect();
+    let text = serde_json::to_string_pretty(&values).map_err(|e| file_error("write", &file, e))?;
+    tokio::fs::write(&file, text).await.map_err(|e| file_error("write", &file, e))?;
+    Ok(())
+  }
+
+  // The async lock is held across file IO so writes to a collection land in order.
+  async fn with_collection<T>(
+    &self, collection: &str, action: impl FnOnce(&mut Vec<Document>) -> (T, bool),
+  ) -> Result<T, ServerFnError> {
+    let mut collections = self.collections.lock().await;
+    if !collections.contains_key(collection) {
+      let documents = self.load(collection).await?;
+      collections.insert(collection.into(), documents);
+    }
+    let documents = collections.entry(collection.into()).or_default();
+    let (result, changed) = action(documents);
+    if changed {
+      self.save(collection, documents).await?;
+    }
+    Ok(result)
+  }
+}
+
+fn file_error(action: &str, file: &std::path::Path, error: impl std::fmt::Display) -> ServerFnError {
+  tracing::error!("Unable to {} {} {}", action, file.display(), error);
+  return ServerFnError::new(format!("Unable to {action} {}: {error}", file.display()));
+}
+
+fn same_value(left: &Bson, right: &Bson) -> bool {
+  return match (left, right) {
+    (Bson::Int32(left), Bson::Int64(right)) => *left as i64 == *right,
+    (Bson::Int64(left), Bson::Int32(right)) => *left == *right as i64,
+    _ => left == right,
+  };
+}
+
+fn matches(document: &Document, filter: &Document) -> bool {
+  return filter.iter().all(|(key, expected)| match expected {
+    Bson::Document(operator) if operator.contains_key("$exists") => {
+      let exists = operator.get_bool("$exists").unwrap_or(true);
+      document.contains_key(key) == exists
+    },
+    _ => document.get(key).is_some_and(|value| same_value(value, expected)),
+  });
+}
+
+impl Storage for LocalStorage {
+  async fn find(&self, collection: &str, filter: Document) -> Result<Vec<Document>, ServerFnError> {
+    return self
+      .with_collection(collection, |documents| {
+        let found = documents
+          .iter()
+          .filter(|document| matches(document, &filter))
+          .cloned()
+          .collect();
+        (found, false)
+      })
+      .await;
+  }
+
+  async fn find_latest(
+    &self, collection: &str, filter: Document, limit: usize,
+  ) -> Result<Vec<Document>, ServerFnError> {
+    return self
+      .with_collection(collection, |documents| {
+        let found: Vec<Document> =
+          documents.iter().filter(|document| matches(document, &filter)).cloned().collect();
+        let skipped = found.len().saturating_sub(limit);
+        (found.into_iter().skip(skipped).collect(), false)
+      })
+      .await;
+  }
+
+  async fn insert(&self, collection: &str, document: Document) -> Result<(), ServerFnError> {
+    return self
+      .with_collection(collection, |documents| {
+        documents.push(document);
+        ((), true)
+      })
+      .await;
+  }
+
+  async fn insert_unique(
+    &self, collection: &str, filter: Document, document: Document,
+  ) -> Result<bool, ServerFnError> {
+    return self
+      .with_collection(collection, |documents| {
+        if documents.iter().any(|existing| matches(existing, &filter)) {
+          return (false, false);
+        }
+        documents.push(document);
+        (true, true)
+      })
+      .await;
+  }
+
+  async fn replace(
+    &self, collection: &str, filter: Document, document: Document,
+  ) -> Result<bool, ServerFnError> {
+    return self
+      .with_collection(collection, |documents| {
+        match documents.iter_mut().find(|existing| matches(existing, &filter)) {
+          Some(existing) => {
+            *existing = document;
+            (true, true)
+          },
+          None => (false, false),
+        }
+      })
+      .await;
+  }
+
+  async fn delete(&self, collection: &str, filter: Document) -> Result<bool, ServerFnError> {
+    return self
+      .with_collection(collection, |documents| {
+        match documents.iter().position(|existing| matches(existing, &filter)) {
+          Some(index) => {
+            documents.remove(index);
+            (true, true)
+          },
+          None => (false, false),
+        }
+      })
+      .await;
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use mongodb::bson::doc;
+
+  fn run<T>(future: impl std::future::Future<Output = T>) -> T {
+    return tokio::runtime::Builder::new_current_thread()
+      .enable_all()
+      .build()
+      .expect("Failed to start the tokio runtime")
+      .block_on(future);
+  }
+
+  #[test]
+  fn finds_by_equality_and_existence() {
+    run(async {
+      let storage = LocalStorage::new(None);
+      storage.insert("skills", doc! { "title": "Parry", "revision": 1_i64 }).await.unwrap();
+      storage.insert("skills", doc! { "title": "Riposte" }).await.unwrap();
+      let found = storage.find("skills", doc! { "title": "Parry" }).await.unwrap();
+      assert_eq!(found.len(), 1);
+      let found = storage.find("skills", doc! { "revision": 1 }).await.unwrap();
+      assert_eq!(found.len(), 1);
+      let found = storage.find("skills", doc! { "revision": { "$exists": false } }).await.unwrap();
+      assert_eq!(found[0].get_str("title"), Ok("Riposte"));
+      assert_eq!(storage.find("skills", doc! {}).await.unwrap().len(), 2);
+      assert!(storage.find("paths", doc! {}).await.unwrap().is_empty());
+    });
+  }
+
+  #[test]
+  fn inserts_unique_documents_once() {
+    run(async {
+      let storage = LocalStorage::new(None);
+      let filter = doc! { "characterId": 1, "session": "north" };
+      let first = doc! { "_id": 1, "characterId": 1, "session": "north" };
+      assert!(storage.insert_unique("play_sessions", filter.clone(), first.clone()).await.unwrap());
+      let second = doc! { "_id": 2, "characterId": 1, "session": "north" };
+      assert!(!storage.insert_unique("play_sessions", filter, second).await.unwrap());
+      assert_eq!(storage.find("play_sessions", doc! {}).await.unwrap(), vec![first]);
+    });
+  }
+
+  #[test]
+  fn replaces_only_the_expected_revision() {
+    run(async {
+      let storage = LocalStorage::new(None);
+      storage.insert("creatures", doc! { "_id": 1, "revision": 1 }).await.unwrap();
+      let stale = doc! { "_id": 1, "revision": 0 };
+      let replaced = storage.replace("creatures", stale, doc! { "_id": 1, "revision": 2 });
+      assert!(!replaced.await.unwrap());
+      let current = doc! { "_id": 1, "revision": 1 };
+      let replaced = storage.replace("creatures", current, doc! { "_id": 1, "revision": 2 });
+      assert!(replaced.await.unwrap());
+      let found = storage.find("creatures", doc! { "_id": 1 }).await.unwrap();
+      assert_eq!(found, vec![doc! { "_id": 1, "revision": 2 }]);
+    });
+  }
+
+  #[test]
+  fn deletes_one_matching_document() {
+    run(async {
+      let storage = LocalStorage::new(None);
+      storage.insert("armor", doc! { "_id": 1 }).await.unwrap();
+      storage.insert("armor", doc! { "_id": 2 }).await.unwrap();
+      assert!(storage.delete("armor", doc! { "_id": 1 }).await.unwrap());
+      assert!(!storage.delete("armor", doc! { "_id": 1 }).await.unwrap());
+      assert_eq!(storage.find("armor", doc! {}).await.unwrap(), vec![doc! { "_id": 2 }]);
+    });
+  }
+
+  #[test]
+  fn keeps_the_latest_documents() {
+    run(async {
+      let storage = LocalStorage::new(None);
+      for id in 1..=5 {
+        storage.insert("roll_log", doc! { "_id": id, "campaign": "north" }).await.unwrap();
+      }
+      let found = storage.find_latest("roll_log", doc! { "campaign": "north" }, 2).await.unwrap();
+      let ids: Vec<i32> = found.iter().filter_map(|document| document.get_i32("_id").ok()).collect();
+      assert_eq!(ids, vec![4, 5]);
+    });
+  }
+
+  #[test]
+  fn directory_storage_survives_a_restart() {
+    let directory = std::env::temp_dir().join(format!("relic-storage-{}", std::process::id()));
+    run(async {
+      let storage = LocalStorage::new(Some(directory.clone()));
+      storage.insert("keywords", doc! { "_id": 1, "title": "Burning" }).await.unwrap();
+      let reopened = LocalStorage::new(Some(directory.clone()));
+      let found = reopened.find("keywords", doc! { "_id": 1 }).await.unwrap();
+      assert_eq!(found[0].get_str("title"), Ok("Burning"));
+    });
+    let _ = std::fs::remove_dir_all(&directory);
+  }
+}
@@ -0,0 +1,135 @@
+mod local;
+mod mongo;
+
+use std::future::Future;
+use std::path::PathBuf;
+
+use dioxus::prelude::ServerFnError;
+use mongodb::bson::Document;
+use tokio::sync::OnceCell;
+
+pub use local::LocalStorage;
+use mongo::MongoStorage;
+
+pub trait Storage: Send + Sync {
+  fn find(
+    &self, collection: &str, filter: Document,
+  ) -> impl Future<Output = Result<Vec<Document>, ServerFnError>> + Send;
+
+  fn find_latest(
+    &self, collection: &str, filter: Document, limit: usize,
+  ) -> impl Future<Output = Result<Vec<Document>, ServerFnError>> + Send;
+
+  fn insert(
+    &self, collection: &str, document: Document,
+  ) -> impl Future<Output = Result<(), ServerFnError>> + Send;
+
+  // Inserts the document only when nothing matches the filter, as a single atomic step
+  fn insert_unique(
+    &self, collection: &str, filter: Document, document: Document,
+  ) -> impl Future<Output = Result<bool, ServerFnError>> + Send;
+
+  fn replace(
+    &self, collection: &str, filter: Document, document: Document,
+  ) -> impl Future<Output = Result<bool, ServerFnError>> + Send;
+
+  fn delete(
+    &self, collection: &str, filter: Document,
+  ) -> impl Future<Output = Result<bool, ServerFnError>> + Send;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StorageConfig {
+  Mongo { uri: String, database: String },
+  Directory(PathBuf),
+  Memory,
+}
+
+impl StorageConfig {
+  pub fn from_env() -> Self {
+    let database = std::env::var("RELIC_DATABASE").unwrap_or_else(|_| "relic".into());
+    let uri = std::env::var("MONGO_URI").unwrap_or_else(|_| "mongodb://localhost:27017".into());
+    let directory = std::env::var("RELIC_DATA_DIR").unwrap_or_else(|_| "data".into());
+    return match std::env::var("RELIC_STORAGE").unwrap_or_default().to_lowercase().as_str() {
+      "memory" => StorageConfig::Memory,
+      "json" | "directory" => StorageConfig::Directory(directory.into()),
+      _ => StorageConfig::Mongo { uri, database },
+    };
+  }
+}
+
+pub enum StorageBackend {
+  Mongo(MongoStorage),
+  Local(LocalStorage),
+}
+
+impl StorageBackend {
+  pub async fn connect(config: StorageConfig) -> Self {
+    tracing::info!("Using storage backend {:?}", config);
+    return match config {
+      StorageConfig::Mongo { uri, database } => {
+        StorageBackend::Mongo(MongoStorage::connect(&uri, &database).await)
+      },
+      StorageConfig::Directory(directory) => StorageBackend::Local(LocalStorage::new(Some(directory))),
+      StorageConfig::Memory => StorageBackend::Local(LocalStorage::new(None)),
+    };
+  }
+}
+
+impl Storage for StorageBackend {
+  async fn find(&self, collection: &str, filter: Document) -> Result<Vec<Document>, ServerFnError> {
+    return match self {
+      StorageBackend::Mongo(storage) => storage.find(collection, filter).await,
+      StorageBackend::Local(storage) => storage.find(collection, filter).await,
+    };
+  }
+
+  async fn find_latest(
+    &self, collection: &str, filter: Document, limit: usize,
+  ) -> Result<Vec<Document>, ServerFnError> {
+    return match self {
+      StorageBackend::Mongo(storage) => storage.find_latest(collection, filter, limit).await,
+      StorageBackend::Local(storage) => storage.find_latest(collection, filter, limit).await,
+    };
+  }
+
+  async fn insert(&self, collection: &str, document: Document) -> Result<(), ServerFnError> {
+    return match self {
+      StorageBackend::Mongo(storage) => storage.insert(collection, document).await,
+      StorageBackend::Local(storage) => storage.insert(collection, document).await,
+    };
+  }
+
+  async fn insert_unique(
+    &self, collection: &str, filter: Document, document: Document,
+  ) -> Result<bool, ServerFnError> {
+    return match self {
+      StorageBackend::Mongo(storage) => storage.insert_unique(collection, filter, document).await,
+      StorageBackend::Local(storage) => storage.insert_unique(collection, filter, document).await,
+    };
+  }
+
+  async fn replace(
+    &self, collection: &str, filter: Document, document: Document,
+  ) -> Result<bool, ServerFnError> {
+    return match self {
+      StorageBackend::Mongo(storage) => storage.replace(collection, filter, document).await,
+      StorageBackend::Local(storage) => storage.replace(collection, filter, document).await,
+    };
+  }
+
+  async fn delete(&self, collection: &str, filter: Document) -> Result<bool, ServerFnError> {
+    return match self {
+      StorageBackend::Mongo(storage) => storage.delete(collection, filter).await,
+      StorageBackend::Local(storage) => storage.delete(collection, filter).await,
+    };
+  }
+}
+
+static STORAGE: OnceCell<StorageBackend> = OnceCell::const_new();
+
+pub async fn storage() -> &'static StorageBackend {
+  STORAGE
+    .get_or_init(|| async { StorageBackend::connect(StorageConfig::from_env()).await })
+    .await
+}
@@ -0,0 +1,122 @@
+use dioxus::prelude::ServerFnError;
+use futures::StreamExt;
+use mongodb::bson::{doc, Bson, Document};
+use mongodb::error::{ErrorKind, WriteFailure};
+use mongodb::options::IndexOptions;
+use mongodb::{Client, Collection, IndexModel};
+
+use super::Storage;
+
+pub struct MongoStorage {
+  client: Result<Client, String>,
+  database: String,
+}
+
+impl MongoStorage {
+  pub async fn connect(uri: &str, database: &str) -> Self {
+    let client = Client::with_uri_str(uri).await.map_err(|e| {
+      tracing::error!("Unable to initialize MongoDB client {}", e);
+      e.to_string()
+    });
+    return MongoStorage { client, database: database.into() };
+  }
+
+  fn collection(&self, name: &str) -> Result<Collection<Document>, ServerFnError> {
+    return match &self.client {
+      Ok(client) => Ok(client.database(&self.database).collection::<Document>(name)),
+      Err(error) => Err(ServerFnError::new(format!("MongoDB is unavailable: {error}"))),
+    };
+  }
+}
+
+fn storage_error(action: &str, collection: &str, error: mongodb::error::Error) -> ServerFnError {
+  tracing::error!("Unable to {} {} {}", action, collection, error);
+  return ServerFnError::new(error.to_string());
+}
+
+fn is_duplicate_key(error: &mongodb::error::Error) -> bool {
+  return matches!(
+    &*error.kind,
+    ErrorKind::Write(WriteFailure::WriteError(failure)) if failure.code == 11000
+  );
+}
+
+impl Storage for MongoStorage {
+  async fn find(&self, collection: &str, filter: Document) -> Result<Vec<Document>, ServerFnError> {
+    let mut cursor = self
+      .collection(collection)?
+      .find(filter)
+      .await
+      .map_err(|e| storage_error("find", collection, e))?;
+    let mut documents = Vec::new();
+    while let Some(result) = cursor.next().await {
+      documents.push(result.map_err(|e| storage_error("read", collection, e))?);
+    }
+    Ok(documents)
+  }
+
+  async fn find_latest(
+    &self, collection: &str, filter: Document, limit: usize,
+  ) -> Result<Vec<Document>, ServerFnError> {
+    let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+    let mut cursor = self
+      .collection(collection)?
+      .find(filter)
+      .sort(doc! { "_id": -1 })
+      .limit(limit)
+      .await
+      .map_err(|e| storage_error("find", collection, e))?;
+    let mut documents = Vec::new();
+    while let Some(result) = cursor.next().await {
+      documents.push(result.map_err(|e| storage_error("read", collection, e))?);
+    }
+    documents.reverse();
+    Ok(documents)
+  }
+
+  async fn insert(&self, collection: &str, document: Document) -> Result<(), ServerFnError> {
+    self
+      .collection(collection)?
+      .insert_one(document)
+      .await
+      .map_err(|e| storage_error("insert into", collection, e))?;
+    Ok(())
+  }
+
+  async fn insert_unique(
+    &self, collection: &str, filter: Document, document: Document,
+  ) -> Result<bool, ServerFnError> {
+    let target = self.collection(collection)?;
+    // A unique index on the filter keys keeps concurrent upserts from both inserting
+    let keys: Document = filter.keys().map(|key| (key.clone(), Bson::Int32(1))).collect();
+    let options = IndexOptions::builder().unique(true).build();
+    let index = IndexModel::builder().keys(keys).options(options).build();
+    target.create_index(index).await.map_err(|e| storage_error("index", collection, e))?;
+    let result = target.update_one(filter, doc! { "$setOnInsert": document }).upsert(true).await;
+    return match result {
+      Ok(result) => Ok(result.upserted_id.is_some()),
+      Err(error) if is_duplicate_key(&error) => Ok(false),
+      Err(error) => Err(storage_error("insert into", collection, error)),
+    };
+  }
+
+  async fn replace(
+    &self, collection: &str, filter: Document, document: Document,
+  ) -> Result<bool, ServerFnError> {
+    let result = self
+      .collection(collection)?
+      .replace_one(filter, document)
+      .await
+      .map_err(|e| storage_error("replace in", collection, e))?;
+    Ok(result.matched_count > 0)
+  }
+
+  async fn delete(&self, collection: &str, filter: Document) -> Result<bool, ServerFnError> {
+    let result = self
+      .collection(collection)?
+      .delete_one(filter)
+      .await
+      .map_err(|e| storage_error("delete from", collection, e))?;
+    Ok(result.deleted_count > 0)
+  }
+}
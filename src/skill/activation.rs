@@ -130,3 +130,19 @@ impl fmt::Display for Activation {
     )
   }
 }
+
+impl Activation {
+  pub fn ordered() -> Vec<Activation> {
+    return vec![
+      Activation::Boon,
+      Activation::Action,
+      Activation::Interaction,
+      Activation::Reaction,
+      Activation::Reflex,
+      Activation::Trigger,
+      Activation::ComplexAction,
+      Activation::ExtendedAction,
+      Activation::FreeAction,
+    ];
+  }
+}
@@ -36,10 +36,23 @@ impl fmt::Display for TrainingCost {
   }
 }
 
+impl TrainingCost {
+  pub fn ordered() -> Vec<TrainingCost> {
+    return vec![
+      TrainingCost::Inherient,
+      TrainingCost::Keystone,
+      TrainingCost::Full,
+      TrainingCost::Half,
+      TrainingCost::Cantrip,
+      TrainingCost::Spell,
+    ];
+  }
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Default, PartialOrd, Ord, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct RelicOrdering {
-  category: i32,
+  pub category: i32,
 }
 
 impl Skill {
@@ -40,21 +40,36 @@ impl fmt::Display for ResourcePool {
 }
 
 impl ResourcePool {
-  pub fn drain(&self) -> String {
+  pub fn ordered() -> Vec<ResourcePool> {
+    return vec![
+      ResourcePool::Anointment,
+      ResourcePool::Animalism,
+      ResourcePool::Sanguine,
+      ResourcePool::Rage,
+      ResourcePool::Channel,
+      ResourcePool::Ki,
+      ResourcePool::Mastery,
+      ResourcePool::Virtuoso,
+      ResourcePool::MinorMana,
+      ResourcePool::ModerateMana,
+      ResourcePool::MajorMana,
+    ];
+  }
+
+  pub fn drain(&self) -> DrainDie {
     match self {
-      ResourcePool::Anointment => "d6",
-      ResourcePool::Animalism => "d8",
-      ResourcePool::Sanguine => "d10",
-      ResourcePool::Rage => "d12",
-      ResourcePool::Mastery => "d6",
-      ResourcePool::Channel => "d8",
-      ResourcePool::Ki => "d8",
-      ResourcePool::Virtuoso => "d10",
-      ResourcePool::MinorMana => "d10",
-      ResourcePool::ModerateMana => "d10 **1-3",
-      ResourcePool::MajorMana => "d10 **1-4",
+      ResourcePool::Anointment => DrainDie::new(6, 2),
+      ResourcePool::Animalism => DrainDie::new(8, 2),
+      ResourcePool::Sanguine => DrainDie::new(10, 2),
+      ResourcePool::Rage => DrainDie::new(12, 2),
+      ResourcePool::Mastery => DrainDie::new(6, 2),
+      ResourcePool::Channel => DrainDie::new(8, 2),
+      ResourcePool::Ki => DrainDie::new(8, 2),
+      ResourcePool::Virtuoso => DrainDie::new(10, 2),
+      ResourcePool::MinorMana => DrainDie::new(10, 2),
+      ResourcePool::ModerateMana => DrainDie::new(10, 3),
+      ResourcePool::MajorMana => DrainDie::new(10, 4),
     }
-    .into()
   }
 
   pub fn with_drain(&self) -> String {
@@ -62,14 +77,82 @@ impl ResourcePool {
   }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DrainEffect {
+  Drain(i32),
+}
+
+impl fmt::Display for DrainEffect {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      DrainEffect::Drain(amount) => write!(f, "{amount} drain"),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DrainThreshold {
+  pub low: i32,
+  pub high: i32,
+  pub effect: DrainEffect,
+}
+
+impl DrainThreshold {
+  pub fn contains(&self, roll: i32) -> bool {
+    return self.low <= roll && roll <= self.high;
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DrainDie {
+  pub sides: i32,
+  pub thresholds: Vec<DrainThreshold>,
+}
+
+impl DrainDie {
+  pub fn new(sides: i32, drains_on: i32) -> Self {
+    return Self {
+      sides,
+      thresholds: vec![DrainThreshold { low: 1, high: drains_on, effect: DrainEffect::Drain(1) }],
+    };
+  }
+
+  pub fn effect(&self, roll: i32) -> Option<&DrainEffect> {
+    return self
+      .thresholds
+      .iter()
+      .find(|threshold| threshold.contains(roll))
+      .map(|threshold| &threshold.effect);
+  }
+
+  pub fn drain_for(&self, roll: i32) -> i32 {
+    return match self.effect(roll) {
+      Some(DrainEffect::Drain(amount)) => *amount,
+      None => 0,
+    };
+  }
+}
+
+impl fmt::Display for DrainDie {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "d{}", self.sides)?;
+    for threshold in &self.thresholds {
+      if threshold.low != 1 || threshold.high != 2 {
+        write!(f, " **{}-{}", threshold.low, threshold.high)?;
+      }
+    }
+    return Ok(());
+  }
+}
+
 #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct ResourceCost {
-  resource: ResourcePool,
-  base_cost: Option<i32>,
-  charge_cost: Option<i32>,
-  optional: Option<bool>,
-  max_charges: Option<i32>,
+  pub resource: ResourcePool,
+  pub base_cost: Option<i32>,
+  pub charge_cost: Option<i32>,
+  pub optional: Option<bool>,
+  pub max_charges: Option<i32>,
 }
 
 impl ResourceCost {
@@ -82,7 +165,7 @@ impl ResourceCost {
   pub fn format(&self, drain: bool) -> String {
     let mut components: Vec<String> = Vec::new();
     let drain_option = if drain {
-      Some(self.resource.drain())
+      Some(self.resource.drain().to_string())
     } else {
       None
     };
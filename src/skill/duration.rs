@@ -28,6 +28,42 @@ pub enum DurationClass {
   Months,
 }
 
+impl fmt::Display for DurationClass {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        DurationClass::Custom => "Custom",
+        DurationClass::NextTurnStart => "Next Turn Start",
+        DurationClass::NextTurnEnd => "Next Turn End",
+        DurationClass::WhileReserved => "While Reserved",
+        DurationClass::Minutes => "Minutes",
+        DurationClass::Hours => "Hours",
+        DurationClass::Days => "Days",
+        DurationClass::Weeks => "Weeks",
+        DurationClass::Months => "Months",
+      }
+    )
+  }
+}
+
+impl DurationClass {
+  pub fn ordered() -> Vec<DurationClass> {
+    return vec![
+      DurationClass::Custom,
+      DurationClass::NextTurnStart,
+      DurationClass::NextTurnEnd,
+      DurationClass::WhileReserved,
+      DurationClass::Minutes,
+      DurationClass::Hours,
+      DurationClass::Days,
+      DurationClass::Weeks,
+      DurationClass::Months,
+    ];
+  }
+}
+
 impl Duration {
   pub fn five_min() -> Self {
     Self {
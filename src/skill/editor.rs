@@ -0,0 +1,478 @@
+use dioxus::prelude::*;
+
+use crate::common::*;
+use crate::keyword::prelude::*;
+use crate::rules::prelude::*;
+use crate::server::prelude::*;
+use crate::skill::component::SkillCard;
+use crate::skill::prelude::Action;
+use crate::skill::prelude::*;
+use crate::Route;
+
+fn block_text(block: &RulesBlock) -> Option<String> {
+  return match block.as_slice() {
+    [snippet] if snippet.roll.is_none() && snippet.term.is_none() => snippet.text.clone(),
+    _ => None,
+  };
+}
+
+fn sections_text(sections: &Option<RuleSections>) -> Option<String> {
+  let Some(sections) = sections else {
+    return Some(String::new());
+  };
+  return match sections.as_slice() {
+    [section] if section.items.is_none() => section.block.as_ref().and_then(block_text),
+    _ => None,
+  };
+}
+
+fn stacks_text(stacks: &Option<RuleStacks>) -> Option<String> {
+  let Some(stacks) = stacks else {
+    return Some(String::new());
+  };
+  return match stacks.as_slice() {
+    [stack]
+      if stack.property.is_none()
+        && stack.outcomes.is_none()
+        && stack.stats.is_none()
+        && stack.items.is_none() =>
+    {
+      stack.block.as_ref().and_then(block_text)
+    },
+    _ => None,
+  };
+}
+
+fn sections_from_text(text: String) -> Option<RuleSections> {
+  return match text.is_empty() {
+    true => None,
+    false => rule_sections_from_blurb(text),
+  };
+}
+
+fn stacks_from_text(text: String) -> Option<RuleStacks> {
+  return match text.is_empty() {
+    true => None,
+    false => rules_stack_from_blurb(text),
+  };
+}
+
+#[component]
+pub fn SkillEditor(skill: Skill, #[props(default)] is_new: bool) -> Element {
+  let SkillCache(skill_cache) = use_context::<SkillCache>();
+  let mut draft: Signal<Skill> = use_signal(|| skill.clone());
+  let mut status: Signal<SaveStatus> = use_signal(|| SaveStatus::Idle);
+  let nav = navigator();
+  let current = draft();
+  let valid = !current.title.trim().is_empty();
+  let saving = status() == SaveStatus::Saving;
+  let save_class = if saving || !valid { "disabled" } else { "" };
+  let sub_actions = current.sub_actions.clone().unwrap_or_default();
+  rsx! {
+    div {
+      class: "row gap-large align-start",
+      div {
+        class: "card grid dim-keywords no-print",
+        FormRow {
+          title: "Title",
+          TextField { value: current.title.clone(), onchange: move |value| draft.write().title = value }
+        }
+        FormRow {
+          title: "Summary",
+          OptionalTextField { value: current.summary.clone(), onchange: move |value| draft.write().summary = value }
+        }
+        FormRow {
+          title: "Description",
+          OptionalTextField {
+            value: current.description.clone(),
+            multiline: true,
+            onchange: move |value| draft.write().description = value,
+          }
+        }
+        FormRow {
+          title: "Tier",
+          EnumSelect {
+            options: Tier::ordered(),
+            selected: Some(current.tier.clone()),
+            onchange: move |value: Option<Tier>| if let Some(tier) = value { draft.write().tier = tier },
+          }
+        }
+        FormRow {
+          title: "Training",
+          EnumSelect {
+            options: TrainingCost::ordered(),
+            selected: Some(current.training_cost.clone()),
+            onchange: move |value: Option<TrainingCost>| {
+              if let Some(training_cost) = value { draft.write().training_cost = training_cost }
+            },
+          }
+          "Ranked"
+          OptionalFlagField { value: current.ranked, onchange: move |value| draft.write().ranked = value }
+          "Core"
+          OptionalFlagField { value: current.core, onchange: move |value| draft.write().core = value }
+        }
+        FormRow {
+          title: "Order",
+          NumberField {
+            value: current.order.category,
+            onchange: move |value| draft.write().order = RelicOrdering { category: value },
+          }
+        }
+        div { class: "uv-full subheading", "Action" }
+        ActionEditor {
+          action: current.action.clone(),
+          onchange: move |action| draft.write().action = action,
+        }
+        for (index, action) in sub_actions.into_iter().enumerate() {
+          div {
+            class: "uv-full row align-center",
+            div { class: "subheading", "Sub Action {index + 1}" }
+            div {
+              class: "chip",
+              onclick: move |_| {
+                let mut skill = draft.write();
+                if let Some(sub_actions) = skill.sub_actions.as_mut() {
+                  sub_actions.remove(index);
+                }
+                if skill.sub_actions.as_ref().is_some_and(|sub_actions| sub_actions.is_empty()) {
+                  skill.sub_actions = None;
+                }
+              },
+              "Remove ✕"
+            }
+          }
+          ActionEditor {
+            action,
+            onchange: move |action| {
+              if let Some(sub_actions) = draft.write().sub_actions.as_mut() {
+                sub_actions[index] = action;
+              }
+            },
+          }
+        }
+        div {
+          class: "uv-full row gap-large spacer",
+          div {
+            class: "next-button",
+            onclick: move |_| {
+              draft.write().sub_actions.get_or_insert_with(Vec::new).push(Action::default());
+            },
+            "Add sub action"
+          }
+          div {
+            class: "next-button {save_class}",
+            onclick: move |_| {
+              if saving || !valid {
+                return;
+              }
+              let skill = draft();
+              let cache = skill_cache.clone();
+              status.set(SaveStatus::Saving);
+              spawn(async move {
+                let result = match is_new {
+                  true => insert_skill(skill).await,
+                  false => update_skill(skill).await,
+                };
+                match result {
+                  Ok(id) => {
+                    status.set(SaveStatus::Idle);
+                    cache.restart();
+                    nav.push(Route::SingleSkillPage { id });
+                  },
+                  Err(error) => status.set(SaveStatus::Errored(error.to_string())),
+                }
+              });
+            },
+            if saving { "Saving ..." } else { "Save skill" }
+          }
+        }
+        if let SaveStatus::Errored(error) = status() {
+          div { class: "uv-full errored", "Unable to save skill: {error}" }
+        }
+      }
+      div {
+        class: "column",
+        SkillCard { skill: current }
+      }
+    }
+  }
+}
+
+#[component]
+pub fn ActionEditor(action: Action, onchange: EventHandler<Action>) -> Element {
+  let extended = action.class == Activation::ExtendedAction;
+  rsx! {
+    FormRow {
+      title: "Activation",
+      EnumSelect {
+        options: Activation::ordered(),
+        selected: Some(action.class.clone()),
+        onchange: edit_with(&action, onchange, |action, value: Option<Activation>| {
+          if let Some(class) = value {
+            action.class = class;
+          }
+        }),
+      }
+      "Initial"
+      OptionalFlagField {
+        value: action.initial,
+        onchange: edit_with(&action, onchange, |action, value| action.initial = value),
+      }
+    }
+    FormRow {
+      title: "Sub Title",
+      OptionalTextField {
+        value: action.sub_title.clone(),
+        onchange: edit_with(&action, onchange, |action, value| action.sub_title = value),
+      }
+    }
+    FormRow {
+      title: "Keywords",
+      KeywordPicker {
+        selected: action.keyword_ids.clone().unwrap_or_default(),
+        onchange: edit_with(&action, onchange, |action, value: Vec<_>| {
+          action.keyword_ids = if value.is_empty() { None } else { Some(value) };
+        }),
+      }
+    }
+    RulesTextField {
+      title: "Condition",
+      text: sections_text(&action.condition),
+      onchange: edit_with(&action, onchange, |action, value| action.condition = sections_from_text(value)),
+    }
+    CostEditor {
+      title: "Cost",
+      cost: action.cost.clone(),
+      onchange: edit_with(&action, onchange, |action, value| action.cost = value),
+    }
+    DurationEditor {
+      title: "Duration",
+      duration: action.duration.clone(),
+      onchange: edit_with(&action, onchange, |action, value| action.duration = value),
+    }
+    if extended {
+      DurationEditor {
+        title: "Extended",
+        duration: action.extended_duration.clone(),
+        onchange: edit_with(&action, onchange, |action, value| action.extended_duration = value),
+      }
+    }
+    TargetEditor {
+      target: action.target.clone(),
+      onchange: edit_with(&action, onchange, |action, value| action.target = value),
+    }
+    RulesTextField {
+      title: "Refresh",
+      text: sections_text(&action.refresh),
+      onchange: edit_with(&action, onchange, |action, value| action.refresh = sections_from_text(value)),
+    }
+    RulesTextField {
+      title: "Rules",
+      text: stacks_text(&action.rules),
+      onchange: edit_with(&action, onchange, |action, value| action.rules = stacks_from_text(value)),
+    }
+  }
+}
+
+#[component]
+fn RulesTextField(title: String, text: Option<String>, onchange: EventHandler<String>) -> Element {
+  rsx! {
+    FormRow {
+      title,
+      match text {
+        Some(value) => rsx! { TextField { value, multiline: true, onchange } },
+        None => rsx! { div { class: "italics", "Structured rules are kept as authored" } },
+      }
+    }
+  }
+}
+
+#[component]
+fn CostEditor(
+  title: String, cost: Option<ResourceCost>, onchange: EventHandler<Option<ResourceCost>>,
+) -> Element {
+  let enabled = cost.is_some();
+  let cost = cost.unwrap_or_default();
+  let on_cost = EventHandler::new(move |cost: ResourceCost| onchange.call(Some(cost)));
+  let pools = ResourcePool::ordered();
+  let labels = pools.iter().map(|pool| pool.with_drain()).collect::<Vec<String>>();
+  rsx! {
+    FormRow {
+      title,
+      CheckboxField {
+        checked: enabled,
+        onchange: move |checked| onchange.call(if checked { Some(ResourceCost::default()) } else { None }),
+      }
+      if enabled {
+        EnumSelect {
+          options: pools,
+          labels,
+          selected: Some(cost.resource.clone()),
+          onchange: edit_with(&cost, on_cost, |cost, value: Option<ResourcePool>| {
+            if let Some(resource) = value {
+              cost.resource = resource;
+            }
+          }),
+        }
+        "Base"
+        OptionalNumberField {
+          value: cost.base_cost,
+          onchange: edit_with(&cost, on_cost, |cost, value| cost.base_cost = value),
+        }
+        "Charge"
+        OptionalNumberField {
+          value: cost.charge_cost,
+          onchange: edit_with(&cost, on_cost, |cost, value| cost.charge_cost = value),
+        }
+        "Max"
+        OptionalNumberField {
+          value: cost.max_charges,
+          onchange: edit_with(&cost, on_cost, |cost, value| cost.max_charges = value),
+        }
+      }
+    }
+  }
+}
+
+#[component]
+fn DurationEditor(
+  title: String, duration: Option<Duration>, onchange: EventHandler<Option<Duration>>,
+) -> Element {
+  let enabled = duration.is_some();
+  let duration = duration.unwrap_or_default();
+  let on_duration = EventHandler::new(move |duration: Duration| onchange.call(Some(duration)));
+  let custom = duration.class == DurationClass::Custom;
+  rsx! {
+    FormRow {
+      title,
+      CheckboxField {
+        checked: enabled,
+        onchange: move |checked| onchange.call(if checked { Some(Duration::default()) } else { None }),
+      }
+      if enabled {
+        EnumSelect {
+          options: DurationClass::ordered(),
+          selected: Some(duration.class.clone()),
+          onchange: edit_with(&duration, on_duration, |duration, value: Option<DurationClass>| {
+            if let Some(class) = value {
+              duration.class = class;
+            }
+          }),
+        }
+        "Length"
+        OptionalNumberField {
+          value: duration.length,
+          onchange: edit_with(&duration, on_duration, |duration, value| duration.length = value),
+        }
+        "Expendable"
+        OptionalFlagField {
+          value: duration.expendable,
+          onchange: edit_with(&duration, on_duration, |duration, value| duration.expendable = value),
+        }
+        "Upkeep"
+        OptionalFlagField {
+          value: duration.upkeep,
+          onchange: edit_with(&duration, on_duration, |duration, value| duration.upkeep = value),
+        }
+      }
+    }
+    if enabled && custom {
+      FormRow {
+        title: "Custom",
+        OptionalTextField {
+          value: duration.custom.clone(),
+          onchange: edit_with(&duration, on_duration, |duration, value| duration.custom = value),
+        }
+      }
+    }
+    if enabled {
+      CostEditor {
+        title: "Upkeep Cost",
+        cost: duration.upkeep_cost.clone(),
+        onchange: edit_with(&duration, on_duration, |duration, value| duration.upkeep_cost = value),
+      }
+    }
+  }
+}
+
+#[component]
+fn TargetEditor(target: Option<Target>, onchange: EventHandler<Option<Target>>) -> Element {
+  let enabled = target.is_some();
+  let target = target.unwrap_or_default();
+  let on_target = EventHandler::new(move |target: Target| onchange.call(Some(target)));
+  rsx! {
+    FormRow {
+      title: "Target",
+      CheckboxField {
+        checked: enabled,
+        onchange: move |checked| onchange.call(if checked { Some(Target::default()) } else { None }),
+      }
+      if enabled {
+        EnumSelect {
+          options: TargetClass::ordered(),
+          selected: Some(target.class.clone()),
+          onchange: edit_with(&target, on_target, |target, value: Option<TargetClass>| {
+            if let Some(class) = value {
+              target.class = class;
+            }
+          }),
+        }
+        EnumSelect {
+          options: Selection::ordered(),
+          optional: true,
+          selected: target.selection.clone(),
+          onchange: edit_with(&target, on_target, |target, value| target.selection = value),
+        }
+        "Placed"
+        OptionalFlagField {
+          value: target.placed,
+          onchange: edit_with(&target, on_target, |target, value| target.placed = value),
+        }
+      }
+    }
+    if enabled {
+      FormRow {
+        title: "Area",
+        "Range"
+        OptionalNumberField {
+          value: target.range,
+          onchange: edit_with(&target, on_target, |target, value| target.range = value),
+        }
+        "Charge"
+        OptionalNumberField {
+          value: target.charge_range,
+          onchange: edit_with(&target, on_target, |target, value| target.charge_range = value),
+        }
+        "Size"
+        OptionalNumberField {
+          value: target.size,
+          onchange: edit_with(&target, on_target, |target, value| target.size = value),
+        }
+        "Limit"
+        OptionalNumberField {
+          value: target.limit,
+          onchange: edit_with(&target, on_target, |target, value| target.limit = value),
+        }
+      }
+      FormRow {
+        title: "Wording",
+        OptionalTextField {
+          value: target.custom_selection.clone(),
+          onchange: edit_with(&target, on_target, |target, value| target.custom_selection = value),
+        }
+        "Suffix"
+        OptionalTextField {
+          value: target.suffix.clone(),
+          onchange: edit_with(&target, on_target, |target, value| target.suffix = value),
+        }
+      }
+      FormRow {
+        title: "Custom",
+        OptionalTextField {
+          value: target.custom.clone(),
+          onchange: edit_with(&target, on_target, |target, value| target.custom = value),
+        }
+      }
+    }
+  }
+}
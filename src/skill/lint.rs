@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+
+use super::prelude::*;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ContentLint {
+  pub skill_id: String,
+  pub skill_title: String,
+  pub location: String,
+  pub field: String,
+  pub rendered: String,
+  pub reason: String,
+}
+
+fn is_fallback(rendered: &str) -> bool {
+  return rendered.trim().is_empty() || rendered.to_lowercase().contains("undefined");
+}
+
+fn target_reason(target: &Target) -> String {
+  if target.singular() == "undefined" {
+    return "Targets need a selection or custom selection".into();
+  }
+  let needs = match target.class {
+    TargetClass::Custom => "custom text",
+    TargetClass::Range | TargetClass::Burst => "a range",
+    TargetClass::Line | TargetClass::Cone => "a size without a limit",
+    TargetClass::RadiusCorner | TargetClass::RadiusSpace => "a range and a size",
+    _ => "a selection",
+  };
+  return format!("{} targets need {needs}", target.class);
+}
+
+fn duration_reason(duration: &Duration) -> String {
+  return match duration.class {
+    DurationClass::Custom => "Custom durations need custom text".into(),
+    DurationClass::WhileReserved => "While Reserved durations need an upkeep cost".into(),
+    _ => format!("{} durations cannot be rendered", duration.class),
+  };
+}
+
+fn action_renders(action: &Action) -> Vec<(&'static str, String, String)> {
+  let mut renders = Vec::new();
+  if let Some(suffix) = action.suffix() {
+    let reason = match &action.extended_duration {
+      Some(duration) => duration_reason(duration),
+      None => "Extended actions need an extended duration".into(),
+    };
+    renders.push(("extended duration", suffix, reason));
+  }
+  if let Some(duration) = &action.duration {
+    renders.push(("duration", duration.to_string(), duration_reason(duration)));
+  }
+  if let Some(target) = &action.target {
+    renders.push(("target", target.to_string(), target_reason(target)));
+  }
+  return renders;
+}
+
+impl Skill {
+  pub fn content_lints(&self) -> Vec<ContentLint> {
+    let mut actions = vec![("Action".to_string(), &self.action)];
+    for (index, action) in self.sub_actions.iter().flatten().enumerate() {
+      actions.push((format!("Sub Action {}", index + 1), action));
+    }
+    let mut lints = Vec::new();
+    for (location, action) in actions {
+      for (field, rendered, reason) in action_renders(action) {
+        if is_fallback(&rendered) {
+          lints.push(ContentLint {
+            skill_id: self.id.to_hex(),
+            skill_title: self.title.clone(),
+            location: location.clone(),
+            field: field.into(),
+            rendered,
+            reason,
+          });
+        }
+      }
+    }
+    return lints;
+  }
+}
+
+pub fn lint_skills(skills: &[Skill]) -> Vec<ContentLint> {
+  let mut skills = skills.to_vec();
+  skills.sort();
+  return skills.iter().flat_map(|skill| skill.content_lints()).collect();
+}
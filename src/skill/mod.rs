@@ -3,7 +3,10 @@ mod aspect;
 pub mod component;
 mod cost;
 mod duration;
+pub mod editor;
 mod filters;
+mod lint;
+mod picker;
 mod target;
 
 use std::cmp::Ordering;
@@ -134,8 +137,10 @@ pub mod prelude {
   pub use super::activation::{Action, Activation};
   pub use super::aspect::{Property, RelicOrdering, TrainingCost};
   pub use super::cost::{ResourceCost, ResourcePool};
-  pub use super::duration::Duration;
+  pub use super::duration::{Duration, DurationClass};
   pub use super::filters::{keywords_from_skills};
+  pub use super::lint::{lint_skills, ContentLint};
+  pub(crate) use super::picker::SkillPicker;
   pub use super::target::{Target,TargetClass,Selection};
   pub use super::Skill;
 }
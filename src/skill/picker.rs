@@ -0,0 +1,24 @@
+use bson::oid::ObjectId;
+use dioxus::prelude::*;
+
+use super::Skill;
+use crate::common::{IdPicker, PickerItem};
+use crate::server::prelude::SkillCache;
+
+#[component]
+pub(crate) fn SkillPicker(selected: Vec<ObjectId>, onchange: EventHandler<Vec<ObjectId>>) -> Element {
+  let SkillCache(ref skill_cache) = use_context();
+  let mut skills: Vec<Skill> = skill_cache.into_vec();
+  skills.sort();
+  let items: Vec<PickerItem> = skills
+    .into_iter()
+    .map(|skill| PickerItem {
+      id: skill.id,
+      label: format!("{} ({})", skill.title, skill.tier),
+      title: skill.title,
+    })
+    .collect();
+  rsx! {
+    IdPicker { items, selected, placeholder: "Add skill", onchange }
+  }
+}
@@ -32,6 +32,46 @@ pub enum TargetClass {
   Line,
 }
 
+impl fmt::Display for TargetClass {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        TargetClass::Custom => "Custom",
+        TargetClass::Touch => "Touch",
+        TargetClass::Weapon => "Weapon",
+        TargetClass::Range => "Range",
+        TargetClass::LineOfSight => "Line of Sight",
+        TargetClass::SeeOrHear => "See or Hear",
+        TargetClass::Cone => "Cone",
+        TargetClass::Burst => "Burst",
+        TargetClass::RadiusCorner => "Radius Corner",
+        TargetClass::RadiusSpace => "Radius Space",
+        TargetClass::Line => "Line",
+      }
+    )
+  }
+}
+
+impl TargetClass {
+  pub fn ordered() -> Vec<TargetClass> {
+    return vec![
+      TargetClass::Custom,
+      TargetClass::Touch,
+      TargetClass::Weapon,
+      TargetClass::Range,
+      TargetClass::LineOfSight,
+      TargetClass::SeeOrHear,
+      TargetClass::Cone,
+      TargetClass::Burst,
+      TargetClass::RadiusCorner,
+      TargetClass::RadiusSpace,
+      TargetClass::Line,
+    ];
+  }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, Eq)]
 pub enum Selection {
   Ally,
@@ -47,6 +87,44 @@ pub enum Selection {
   CreatureObjectSpace,
 }
 
+impl fmt::Display for Selection {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        Selection::Ally => "Ally",
+        Selection::Enemy => "Enemy",
+        Selection::Creature => "Creature",
+        Selection::Space => "Space",
+        Selection::Object => "Object",
+        Selection::Affect => "Affect",
+        Selection::CreatureSpace => "Creature or Space",
+        Selection::CreatureObject => "Creature or Object",
+        Selection::ObjectSpace => "Object or Space",
+        Selection::CreatureObjectSpace => "Creature, Object, or Space",
+      }
+    )
+  }
+}
+
+impl Selection {
+  pub fn ordered() -> Vec<Selection> {
+    return vec![
+      Selection::Ally,
+      Selection::Enemy,
+      Selection::Creature,
+      Selection::Space,
+      Selection::Object,
+      Selection::Affect,
+      Selection::CreatureSpace,
+      Selection::CreatureObject,
+      Selection::ObjectSpace,
+      Selection::CreatureObjectSpace,
+    ];
+  }
+}
+
 impl Target {
   pub fn singular(&self) -> String {
     match (&self.selection, &self.custom_selection) {